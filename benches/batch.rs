@@ -0,0 +1,53 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use trapez_integrate::{trapezoid_batch_f64, trapezoid_f64};
+
+fn bench_trapezoid_batch(c: &mut Criterion) {
+    // non-uniform grid shared by 1000 rows
+    let x: Vec<f64> = (0..1000).map(|i| (i as f64 * 1e-3).powi(2)).collect();
+    let rows: Vec<Vec<f64>> = (0..1000)
+        .map(|r| x.iter().map(|&x| (x * r as f64).sin()).collect())
+        .collect();
+    let ys: Vec<&[f64]> = rows.iter().map(|r| r.as_slice()).collect();
+    c.bench_function("trapezoid_f64 1000x1000 loop", |b| {
+        b.iter(|| {
+            ys.iter()
+                .map(|y| trapezoid_f64(black_box(y), black_box(&x)))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("trapezoid_batch_f64 1000x1000", |b| {
+        b.iter(|| trapezoid_batch_f64(black_box(&ys), black_box(&x)))
+    });
+}
+
+criterion_group!(benches, bench_trapezoid_batch);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use trapez_integrate::{trapezoid_f64, trapezoid_signed_f64, trapezoid_with_dx_f64};
+
+fn bench_trapezoid_nonuniform(c: &mut Criterion) {
+    // irregular, strictly increasing grid
+    let x: Vec<f64> = (0..1_000_000)
+        .map(|i| i as f64 * 1e-3 + (i as f64 * 0.37).sin() * 1e-4)
+        .collect();
+    let y: Vec<f64> = x.iter().map(|&x| (x * 0.1).cos()).collect();
+    c.bench_function("trapezoid_f64 1M irregular", |b| {
+        b.iter(|| trapezoid_f64(black_box(&y), black_box(&x)))
+    });
+    // the integration kernel alone, without the monotonicity scan of `trapezoid_f64`
+    c.bench_function("trapezoid_signed_f64 1M irregular", |b| {
+        b.iter(|| trapezoid_signed_f64(black_box(&y), black_box(&x)))
+    });
+    let dx: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+    c.bench_function("trapezoid_with_dx_f64 1M irregular", |b| {
+        b.iter(|| trapezoid_with_dx_f64(black_box(&y), black_box(&dx)))
+    });
+    // the former kernel, one `x[i + 1] - x[i]` per window in a single accumulation chain
+    c.bench_function("per-window loop 1M irregular", |b| {
+        b.iter(|| {
+            let (y, x) = (black_box(&y), black_box(&x));
+            y.windows(2)
+                .zip(x.windows(2))
+                .fold(0., |acc, (y, x)| acc + 0.5 * (x[1] - x[0]) * (y[0] + y[1]))
+        })
+    });
+}
+
+criterion_group!(benches, bench_trapezoid_nonuniform);
+criterion_main!(benches);
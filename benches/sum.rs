@@ -0,0 +1,56 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use trapez_integrate::{trapezoid_even_f32, trapezoid_even_f64};
+
+// Run with and without `--features simd` to compare the scalar and vectorized paths.
+fn bench_trapezoid_even(c: &mut Criterion) {
+    let y: Vec<f64> = (0..10_000_000).map(|i| (i as f64 * 1e-6).sin()).collect();
+    c.bench_function("trapezoid_even_f64 10M", |b| {
+        b.iter(|| trapezoid_even_f64(black_box(&y), black_box(1e-6)))
+    });
+    let y: Vec<f32> = y.iter().map(|&v| v as f32).collect();
+    c.bench_function("trapezoid_even_f32 10M", |b| {
+        b.iter(|| trapezoid_even_f32(black_box(&y), black_box(1e-6)))
+    });
+    // cache resident input, where the kernel width rather than memory bandwidth dominates,
+    // this also tracks the multi-accumulator scalar base case when `simd` is off
+    let y: Vec<f64> = (0..65_536).map(|i| (i as f64 * 1e-3).sin()).collect();
+    c.bench_function("trapezoid_even_f64 64K", |b| {
+        b.iter(|| trapezoid_even_f64(black_box(&y), black_box(1e-3)))
+    });
+    let y: Vec<f32> = y.iter().map(|&v| v as f32).collect();
+    c.bench_function("trapezoid_even_f32 64K", |b| {
+        b.iter(|| trapezoid_even_f32(black_box(&y), black_box(1e-3)))
+    });
+}
+
+criterion_group!(benches, bench_trapezoid_even);
+criterion_main!(benches);
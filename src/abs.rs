@@ -0,0 +1,101 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{classify, validate};
+
+/// Integrates the magnitude `|y|` over `x`, the total area between the curve and the axis.
+///
+/// A segment whose endpoints have opposite signs is split at the zero crossing of the
+/// linear interpolant and both triangles are counted, so it contributes
+/// `0.5 * |dx| * (y[i]^2 + y[i + 1]^2) / (|y[i]| + |y[i + 1]|)` instead of the
+/// `0.5 * |dx| * (|y[i]| + |y[i + 1]|)` a trapezoid over the sample magnitudes gives.
+/// `x` follows the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The non-negative integral, or NaN if the lengths differ, fewer than two
+/// samples are given or `x` is not monotonic.
+pub fn trapezoid_abs_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_abs(y, x)
+}
+
+/// Integrates the magnitude `|y|` over `x`, the total area between the curve and the axis.
+///
+/// A segment whose endpoints have opposite signs is split at the zero crossing of the
+/// linear interpolant and both triangles are counted, so it contributes
+/// `0.5 * |dx| * (y[i]^2 + y[i + 1]^2) / (|y[i]| + |y[i + 1]|)` instead of the
+/// `0.5 * |dx| * (|y[i]| + |y[i + 1]|)` a trapezoid over the sample magnitudes gives.
+/// `x` follows the rules of [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The non-negative integral, or NaN if the lengths differ, fewer than two
+/// samples are given or `x` is not monotonic.
+pub fn trapezoid_abs_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_abs(y, x)
+}
+
+fn trapezoid_abs<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    if validate(y, x).is_err() || classify(x).is_err() {
+        return T::nan();
+    }
+    pairwise_fold(0, y.len() - 1, &|acc, i| {
+        let (a, b) = (y[i].abs(), y[i + 1].abs());
+        let magnitude = if (y[i] < T::zero()) != (y[i + 1] < T::zero()) {
+            // the triangles on both sides of the crossing at a / (a + b) of the segment
+            (a * a + b * b) / (a + b)
+        } else {
+            a + b
+        };
+        acc + T::HALF * (x[i + 1] - x[i]).abs() * magnitude
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_abs() {
+        // the middle segment straddles zero at x = 1.25
+        let x = [0., 1., 2., 3.];
+        let y = [2f64, 1., -3., -3.];
+        let magnitudes: [f64; 4] = core::array::from_fn(|i| y[i].abs());
+        let exact = 1.5 + 0.125 + 1.125 + 3.;
+        assert_eq!(trapezoid_abs_f64(&y, &x), exact);
+        assert_eq!(trapezoid_f64(&magnitudes, &x), 6.5);
+        assert_eq!(trapezoid_f64(&y, &x), -2.5);
+
+        let rev_x = [3., 2., 1., 0.];
+        let rev_y = [-3., -3., 1., 2.];
+        assert_eq!(trapezoid_abs_f64(&rev_y, &rev_x), exact);
+        assert_eq!(trapezoid_abs_f32(&[0., 0.], &[0., 1.]), 0.);
+        assert!(trapezoid_abs_f64(&y, &[0., 2., 1., 3.]).is_nan());
+    }
+}
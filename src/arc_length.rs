@@ -0,0 +1,158 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{classify, validate};
+
+/// Length of the polyline through the points `(x[i], y[i])`.
+///
+/// Sums the chord lengths `sqrt((x[i + 1] - x[i])^2 + (y[i + 1] - y[i])^2)`, the points
+/// may come in any order, e.g. a parametric curve.
+///
+/// # Returns
+/// The arc length, or NaN if the lengths differ or fewer than two points are given.
+pub fn arc_length_f64(x: &[f64], y: &[f64]) -> f64 {
+    arc_length(x, y)
+}
+
+/// Length of the polyline through the points `(x[i], y[i])`.
+///
+/// Sums the chord lengths `sqrt((x[i + 1] - x[i])^2 + (y[i + 1] - y[i])^2)`, the points
+/// may come in any order, e.g. a parametric curve.
+///
+/// # Returns
+/// The arc length, or NaN if the lengths differ or fewer than two points are given.
+pub fn arc_length_f32(x: &[f32], y: &[f32]) -> f32 {
+    arc_length(x, y)
+}
+
+/// Arc length of the graph `y(x)` as the trapezoid integral of `sqrt(1 + y'(x)^2)`.
+///
+/// The derivative at every sample is taken from the parabola through it and its
+/// neighbours, uneven spacing is allowed, so a straight line is measured exactly
+/// like with [arc_length_f64]. `x` must be strictly monotonic.
+///
+/// # Returns
+/// The arc length, or NaN if the lengths differ, fewer than two points are given
+/// or `x` is not monotonic.
+pub fn arc_length_smooth_f64(x: &[f64], y: &[f64]) -> f64 {
+    arc_length_smooth(x, y)
+}
+
+/// Arc length of the graph `y(x)` as the trapezoid integral of `sqrt(1 + y'(x)^2)`.
+///
+/// The derivative at every sample is taken from the parabola through it and its
+/// neighbours, uneven spacing is allowed, so a straight line is measured exactly
+/// like with [arc_length_f32]. `x` must be strictly monotonic.
+///
+/// # Returns
+/// The arc length, or NaN if the lengths differ, fewer than two points are given
+/// or `x` is not monotonic.
+pub fn arc_length_smooth_f32(x: &[f32], y: &[f32]) -> f32 {
+    arc_length_smooth(x, y)
+}
+
+fn arc_length<T: TrapezSample>(x: &[T], y: &[T]) -> T {
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    pairwise_fold(0, x.len() - 1, &|acc: T, i| {
+        acc + (x[i + 1] - x[i]).hypot(y[i + 1] - y[i])
+    })
+}
+
+fn arc_length_smooth<T: TrapezSample>(x: &[T], y: &[T]) -> T {
+    if validate(y, x).is_err() || classify(x).is_err() {
+        return T::nan();
+    }
+    let n = x.len();
+    let slope = |i: usize| {
+        if n == 2 {
+            return (y[1] - y[0]) / (x[1] - x[0]);
+        }
+        // derivative of the parabola through three neighbouring samples
+        let j = i.clamp(1, n - 2);
+        let h1 = x[j] - x[j - 1];
+        let h2 = x[j + 1] - x[j];
+        let (a, b, c) = if i == 0 {
+            (-(h1 + h1 + h2) * h2, (h1 + h2) * (h1 + h2), -h1 * h1)
+        } else if i == n - 1 {
+            (h2 * h2, -(h1 + h2) * (h1 + h2), (h1 + h2 + h2) * h1)
+        } else {
+            (-h2 * h2, h2 * h2 - h1 * h1, h1 * h1)
+        };
+        fmla(a, y[j - 1], fmla(b, y[j], c * y[j + 1])) / (h1 * h2 * (h1 + h2))
+    };
+    let speed = |i: usize| T::one().hypot(slope(i));
+    pairwise_fold(0, n - 1, &|acc, i| {
+        let dx = (x[i + 1] - x[i]).abs();
+        fmla(dx * T::HALF, speed(i) + speed(i + 1), acc)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_length_line() {
+        let x = [0., 1., 3., 3.5];
+        let y = x.map(|x| 2. * x - 1.);
+        let distance = (3.5f64).hypot(7.);
+        assert!((arc_length_f64(&x, &y) - distance).abs() < 1e-14);
+        assert!((arc_length_smooth_f64(&x, &y) - distance).abs() < 1e-14);
+        assert!(arc_length_f32(&[0.], &[0.]).is_nan());
+        assert!(arc_length_smooth_f64(&x, &y[1..]).is_nan());
+    }
+
+    #[test]
+    fn test_arc_length_converges() {
+        let quarter = core::f64::consts::FRAC_PI_2;
+        let mut prev_err = f64::MAX;
+        for n in [4, 16, 64, 256] {
+            let t: Vec<f64> = (0..=n).map(|i| quarter * i as f64 / n as f64).collect();
+            let x: Vec<f64> = t.iter().map(|t| t.cos()).collect();
+            let y: Vec<f64> = t.iter().map(|t| t.sin()).collect();
+            let err = (arc_length_f64(&x, &y) - quarter).abs();
+            assert!(err < prev_err);
+            prev_err = err;
+        }
+        assert!(prev_err < 1e-5);
+
+        // y = x^2 on [0, 1]
+        let exact = (2. * 5f64.sqrt() + 2f64.asinh()) / 4.;
+        let x: Vec<f64> = (0..=64).map(|i| i as f64 / 64.).collect();
+        let y: Vec<f64> = x.iter().map(|x| x * x).collect();
+        let chord_err = (arc_length_f64(&x, &y) - exact).abs();
+        let smooth_err = (arc_length_smooth_f64(&x, &y) - exact).abs();
+        assert!(smooth_err < 1e-4, "{smooth_err}");
+        assert!(chord_err > 0. && chord_err < 1e-4, "{chord_err}");
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Sums `v` using four independent 4-lane accumulators.
+#[target_feature(enable = "avx2")]
+pub(crate) fn sum_f64_avx2(v: &[f64]) -> f64 {
+    let mut acc0 = _mm256_setzero_pd();
+    let mut acc1 = _mm256_setzero_pd();
+    let mut acc2 = _mm256_setzero_pd();
+    let mut acc3 = _mm256_setzero_pd();
+
+    let chunks = v.chunks_exact(16);
+    let rem = chunks.remainder();
+    for chunk in chunks {
+        unsafe {
+            acc0 = _mm256_add_pd(acc0, _mm256_loadu_pd(chunk.as_ptr()));
+            acc1 = _mm256_add_pd(acc1, _mm256_loadu_pd(chunk.as_ptr().add(4)));
+            acc2 = _mm256_add_pd(acc2, _mm256_loadu_pd(chunk.as_ptr().add(8)));
+            acc3 = _mm256_add_pd(acc3, _mm256_loadu_pd(chunk.as_ptr().add(12)));
+        }
+    }
+
+    let acc = _mm256_add_pd(_mm256_add_pd(acc0, acc1), _mm256_add_pd(acc2, acc3));
+    let s = _mm_add_pd(_mm256_castpd256_pd128(acc), _mm256_extractf128_pd::<1>(acc));
+    let s = _mm_add_sd(s, _mm_unpackhi_pd(s, s));
+    let mut sum = _mm_cvtsd_f64(s);
+    for &v in rem.iter() {
+        sum += v;
+    }
+    sum
+}
+
+/// Sums `v` using four independent 8-lane accumulators.
+#[target_feature(enable = "avx2")]
+pub(crate) fn sum_f32_avx2(v: &[f32]) -> f32 {
+    let mut acc0 = _mm256_setzero_ps();
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+
+    let chunks = v.chunks_exact(32);
+    let rem = chunks.remainder();
+    for chunk in chunks {
+        unsafe {
+            acc0 = _mm256_add_ps(acc0, _mm256_loadu_ps(chunk.as_ptr()));
+            acc1 = _mm256_add_ps(acc1, _mm256_loadu_ps(chunk.as_ptr().add(8)));
+            acc2 = _mm256_add_ps(acc2, _mm256_loadu_ps(chunk.as_ptr().add(16)));
+            acc3 = _mm256_add_ps(acc3, _mm256_loadu_ps(chunk.as_ptr().add(24)));
+        }
+    }
+
+    let acc = _mm256_add_ps(_mm256_add_ps(acc0, acc1), _mm256_add_ps(acc2, acc3));
+    let s = _mm_add_ps(_mm256_castps256_ps128(acc), _mm256_extractf128_ps::<1>(acc));
+    let s = _mm_add_ps(s, _mm_movehl_ps(s, s));
+    let s = _mm_add_ss(s, _mm_shuffle_ps::<0b01>(s, s));
+    let mut sum = _mm_cvtss_f32(s);
+    for &v in rem.iter() {
+        sum += v;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sum::pairwise_sum;
+
+    #[test]
+    fn test_sum_avx2() {
+        if !std::arch::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let v: Vec<f64> = (0..10_007).map(|i| (i as f64 * 0.37).sin()).collect();
+        let simd = unsafe { sum_f64_avx2(&v) };
+        assert!((simd - pairwise_sum(&v)).abs() < 1e-10);
+        let v: Vec<f32> = v.iter().map(|&x| x as f32).collect();
+        let simd = unsafe { sum_f32_avx2(&v) };
+        assert!((simd - pairwise_sum(&v)).abs() < 1e-2);
+    }
+}
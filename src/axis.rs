@@ -0,0 +1,79 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::trapezoid::trapezoid;
+use ndarray::{Array1, ArrayView2, Axis};
+
+/// Integrates every lane of a 2D array along `axis` using the trapezoidal rule.
+///
+/// # Arguments
+/// * `arr` - The array of function values.
+/// * `x` - The abscissas shared by all lanes, its length must match the length of `axis`.
+/// * `axis` - The axis to integrate along.
+///
+/// # Returns
+/// One integral per lane. If `x` does not match the axis length every element is NaN.
+pub fn trapezoid_axis<T: TrapezSample>(arr: &ArrayView2<T>, x: &[T], axis: Axis) -> Array1<T> {
+    if arr.len_of(axis) != x.len() {
+        let lanes = arr.len_of(Axis(1 - axis.index()));
+        return Array1::from_elem(lanes, T::nan());
+    }
+    arr.map_axis(axis, |lane| match lane.as_slice() {
+        Some(y) => trapezoid(y, x),
+        None => trapezoid(&lane.to_vec(), x),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+    use ndarray::Array2;
+
+    #[test]
+    fn test_trapezoid_axis() {
+        let x = [1., 2., 4., 6., 7.];
+        let arr = Array2::from_shape_fn((3, 5), |(i, j)| (i as f64 + 1.) * (j as f64).sin());
+        let rows = trapezoid_axis(&arr.view(), &x, Axis(1));
+        assert_eq!(rows.len(), 3);
+        for (row, &v) in arr.rows().into_iter().zip(rows.iter()) {
+            assert_eq!(v, trapezoid_f64(row.as_slice().unwrap(), &x));
+        }
+        // columns are not contiguous in a row-major array
+        let cols = trapezoid_axis(&arr.view(), &x[..3], Axis(0));
+        for (col, &v) in arr.columns().into_iter().zip(cols.iter()) {
+            assert_eq!(v, trapezoid_f64(&col.to_vec(), &x[..3]));
+        }
+        assert!(
+            trapezoid_axis(&arr.view(), &x, Axis(0))
+                .iter()
+                .all(|v| v.is_nan())
+        );
+    }
+}
@@ -0,0 +1,243 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use crate::sum::sum;
+use crate::trapezoid::{Grid, classify};
+use crate::weighted::trapezoid_weight;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "alloc")]
+/// Integrates many series sampled on one shared grid `x`.
+///
+/// The grid is classified once: for uniform spacing every row uses the evenly spaced
+/// rule, otherwise the trapezoid weights of `x` are computed a block at a time and
+/// shared by up to 32 rows, each row being the dot product of its samples with them.
+/// `x` follows the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// One integral per row. Rows whose length differs from `x.len()` give NaN,
+/// every entry is NaN if `x` has fewer than two samples or is not monotonic.
+pub fn trapezoid_batch_f64(ys: &[&[f64]], x: &[f64]) -> Vec<f64> {
+    trapezoid_batch(ys, x)
+}
+
+#[cfg(feature = "alloc")]
+/// Integrates many series sampled on one shared grid `x`.
+///
+/// The grid is classified once: for uniform spacing every row uses the evenly spaced
+/// rule, otherwise the trapezoid weights of `x` are computed a block at a time and
+/// shared by up to 32 rows, each row being the dot product of its samples with them.
+/// `x` follows the rules of [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// One integral per row. Rows whose length differs from `x.len()` give NaN,
+/// every entry is NaN if `x` has fewer than two samples or is not monotonic.
+pub fn trapezoid_batch_f32(ys: &[&[f32]], x: &[f32]) -> Vec<f32> {
+    trapezoid_batch(ys, x)
+}
+
+/// [trapezoid_batch_f64] writing the integrals into a caller-provided buffer.
+///
+/// `out[i]` receives the integral of `ys[i]`, rows whose length differs
+/// from `x.len()` give NaN. Nothing is allocated.
+///
+/// # Errors
+/// * [IntegrateError::OutputLength] if `out.len() != ys.len()`.
+/// * [IntegrateError::TooFewPoints] if `x` has fewer than two samples.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+pub fn trapezoid_batch_into_f64(
+    ys: &[&[f64]],
+    x: &[f64],
+    out: &mut [f64],
+) -> Result<(), IntegrateError> {
+    trapezoid_batch_into(ys, x, out)
+}
+
+/// [trapezoid_batch_f32] writing the integrals into a caller-provided buffer.
+///
+/// `out[i]` receives the integral of `ys[i]`, rows whose length differs
+/// from `x.len()` give NaN. Nothing is allocated.
+///
+/// # Errors
+/// * [IntegrateError::OutputLength] if `out.len() != ys.len()`.
+/// * [IntegrateError::TooFewPoints] if `x` has fewer than two samples.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+pub fn trapezoid_batch_into_f32(
+    ys: &[&[f32]],
+    x: &[f32],
+    out: &mut [f32],
+) -> Result<(), IntegrateError> {
+    trapezoid_batch_into(ys, x, out)
+}
+
+#[cfg(feature = "alloc")]
+fn trapezoid_batch<T: TrapezSample>(ys: &[&[T]], x: &[T]) -> Vec<T> {
+    let mut out = vec![T::nan(); ys.len()];
+    if trapezoid_batch_into(ys, x, &mut out).is_err() {
+        out.fill(T::nan());
+    }
+    out
+}
+
+fn trapezoid_batch_into<T: TrapezSample>(
+    ys: &[&[T]],
+    x: &[T],
+    out: &mut [T],
+) -> Result<(), IntegrateError> {
+    if out.len() != ys.len() {
+        return Err(IntegrateError::OutputLength {
+            expected: ys.len(),
+            actual: out.len(),
+        });
+    }
+    let n = x.len();
+    let grid = classify(x)?;
+
+    if let Some(h0) = grid.step {
+        let sign = if grid.descending { -T::one() } else { T::one() };
+        for (y, dst) in ys.iter().zip(out.iter_mut()) {
+            *dst = if y.len() == n {
+                sign * h0 * fmla(y[0] + y[n - 1], T::HALF, sum(&y[1..n - 1]))
+            } else {
+                T::nan()
+            };
+        }
+        return Ok(());
+    }
+
+    for (rows, out) in ys.chunks(ROW_BLOCK).zip(out.chunks_mut(ROW_BLOCK)) {
+        let integrals = weighted_rows(rows, x, grid, 0, n);
+        for ((y, dst), integral) in rows.iter().zip(out.iter_mut()).zip(integrals) {
+            *dst = if y.len() == n { integral } else { T::nan() };
+        }
+    }
+    Ok(())
+}
+
+/// Rows integrated together against one pass over the weights of a non-uniform grid.
+const ROW_BLOCK: usize = 32;
+/// Samples whose weights and products are held on the stack at once.
+const WEIGHT_BLOCK: usize = 128;
+
+/// Dot products of up to [ROW_BLOCK] `rows` with the trapezoid weights of `x[start..end]`.
+///
+/// The range is split in halves like a pairwise sum until [WEIGHT_BLOCK] samples remain.
+/// Every block of weights is computed once for all rows, and the products of each row
+/// are reduced with [sum], so the build time accumulation strategy applies.
+/// Rows whose length differs from `x.len()` are skipped and left at zero.
+fn weighted_rows<T: TrapezSample>(
+    rows: &[&[T]],
+    x: &[T],
+    grid: Grid<T>,
+    start: usize,
+    end: usize,
+) -> [T; ROW_BLOCK] {
+    if end - start > WEIGHT_BLOCK {
+        let mid = start + (end - start) / 2;
+        let lo = weighted_rows(rows, x, grid, start, mid);
+        let hi = weighted_rows(rows, x, grid, mid, end);
+        return core::array::from_fn(|k| lo[k] + hi[k]);
+    }
+    let mut weights = [T::zero(); WEIGHT_BLOCK];
+    let weights = &mut weights[..end - start];
+    for (w, i) in weights.iter_mut().zip(start..) {
+        *w = trapezoid_weight(x, grid, i);
+    }
+    let mut products = [T::zero(); WEIGHT_BLOCK];
+    let products = &mut products[..end - start];
+    let mut integrals = [T::zero(); ROW_BLOCK];
+    for (y, integral) in rows.iter().zip(integrals.iter_mut()) {
+        if y.len() != x.len() {
+            continue;
+        }
+        for ((p, &w), &y) in products.iter_mut().zip(weights.iter()).zip(&y[start..end]) {
+            *p = w * y;
+        }
+        *integral = sum(products);
+    }
+    integrals
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_batch() {
+        use crate::trapezoid_f64;
+        let x = [1., 2., 4., 6., 7., 9.];
+        let a = [5., 6., 1., 4., 6., 2.];
+        let b = [1., -2., 3., 0.5, 0., 8.];
+        let result = trapezoid_batch_f64(&[&a, &b, &b[1..]], &x);
+        assert_eq!(result[0], 30.5);
+        assert!((result[1] - trapezoid_f64(&b, &x)).abs() < 1e-12);
+        assert!(result[2].is_nan());
+
+        let rev: Vec<f64> = x.iter().rev().copied().collect();
+        let a_rev: Vec<f64> = a.iter().rev().copied().collect();
+        assert_eq!(trapezoid_batch_f64(&[&a_rev], &rev), vec![30.5]);
+
+        // spans several blocks of shared weights and more rows than one row block
+        let x: Vec<f64> = (0..300).map(|i| (i as f64 * 0.01).powi(2)).collect();
+        let y: Vec<f64> = x.iter().map(|x| x.cos()).collect();
+        let mut ys: Vec<&[f64]> = vec![&y; 40];
+        ys[35] = &y[1..];
+        let result = trapezoid_batch_f64(&ys, &x);
+        let reference = trapezoid_f64(&y, &x);
+        assert!(result.iter().enumerate().all(|(i, r)| if i == 35 {
+            r.is_nan()
+        } else {
+            (r - reference).abs() < 1e-12
+        }));
+
+        let even = trapezoid_batch_f32(&[&[5., 6., 1., 4., 6., 2.]], &[0., 0.5, 1., 1.5, 2., 2.5]);
+        assert_eq!(even, vec![10.25]);
+    }
+
+    #[test]
+    fn test_trapezoid_batch_into() {
+        let x = [0., 0.5, 0.75, 2., 3.];
+        let rows = [[1., 2., 3., 4., 5.], [0., -1., 4., 0.5, 2.]];
+        let ys: Vec<&[f64]> = rows.iter().map(|r| r.as_slice()).collect();
+        let mut out = [0.; 2];
+        trapezoid_batch_into_f64(&ys, &x, &mut out).unwrap();
+        assert_eq!(out.to_vec(), trapezoid_batch_f64(&ys, &x));
+        let mut short = [0.; 1];
+        assert_eq!(
+            trapezoid_batch_into_f64(&ys, &x, &mut short),
+            Err(IntegrateError::OutputLength {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+}
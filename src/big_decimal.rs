@@ -0,0 +1,125 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::error::IntegrateError;
+use bigdecimal::{BigDecimal, Zero};
+use core::cmp::Ordering;
+
+/// Calculates the definite integral of arbitrary precision decimal samples using the
+/// trapezoidal rule.
+///
+/// [TrapezSample](crate::TrapezSample) models floating point with NaN as the invalid
+/// result, so decimal input has this separate entry point reporting errors instead.
+/// Sums, products and the final halving are all exact in [BigDecimal], the result is the
+/// trapezoid integral of the given samples without any rounding, which makes it a golden
+/// reference for the floating point routines. Descending abscissas are integrated as if
+/// the samples were reversed, as in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::LengthMismatch] if `y` and `x` lengths differ.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+pub fn try_trapezoid_big(y: &[BigDecimal], x: &[BigDecimal]) -> Result<BigDecimal, IntegrateError> {
+    if x.len() != y.len() {
+        return Err(IntegrateError::LengthMismatch {
+            y: y.len(),
+            x: x.len(),
+        });
+    }
+    if y.len() < 2 {
+        return Err(IntegrateError::TooFewPoints);
+    }
+    let mut descending = None;
+    let mut acc = BigDecimal::zero();
+    for (i, (y, x)) in y.windows(2).zip(x.windows(2)).enumerate() {
+        let dx = &x[1] - &x[0];
+        let down = match dx.cmp(&BigDecimal::zero()) {
+            Ordering::Less => Some(true),
+            Ordering::Greater => Some(false),
+            Ordering::Equal => None,
+        };
+        if let Some(down) = down {
+            match descending {
+                None => descending = Some(down),
+                Some(d) if d != down => return Err(IntegrateError::NonMonotonic { index: i }),
+                _ => {}
+            }
+        }
+        acc += (&y[0] + &y[1]) * dx;
+    }
+    // halving a decimal only appends one digit, 0.5 is exact
+    let integral = acc * BigDecimal::new(5.into(), 1);
+    if descending == Some(true) {
+        Ok(-integral)
+    } else {
+        Ok(integral)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+    use alloc::vec::Vec;
+    use bigdecimal::ToPrimitive;
+
+    #[test]
+    fn test_trapezoid_big() {
+        let big = |v: &[f64]| -> Vec<BigDecimal> {
+            v.iter()
+                .map(|&v| BigDecimal::try_from(v).unwrap())
+                .collect()
+        };
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let exact = try_trapezoid_big(&big(&y), &big(&x)).unwrap();
+        assert_eq!(exact, BigDecimal::try_from(30.5).unwrap());
+
+        // the f64 path rounds every operation, the reference only once at the end
+        let x: Vec<f64> = (0..1000).map(|i| (i as f64 * 1e-3).powf(1.3)).collect();
+        let y: Vec<f64> = x.iter().map(|&v| (v * 5.).sin()).collect();
+        let reference = try_trapezoid_big(&big(&y), &big(&x))
+            .unwrap()
+            .to_f64()
+            .unwrap();
+        let value = trapezoid_f64(&y, &x);
+        assert!(
+            (value - reference).abs() <= 1e-14 * reference.abs(),
+            "{value} vs {reference}"
+        );
+
+        let rev: Vec<f64> = x.iter().rev().copied().collect();
+        let y_rev: Vec<f64> = y.iter().rev().copied().collect();
+        let reversed = try_trapezoid_big(&big(&y_rev), &big(&rev)).unwrap();
+        assert_eq!(reversed.to_f64().unwrap(), reference);
+        assert_eq!(
+            try_trapezoid_big(&big(&[1., 2., 3.]), &big(&[0., 1., 0.5])),
+            Err(IntegrateError::NonMonotonic { index: 1 })
+        );
+    }
+}
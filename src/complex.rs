@@ -0,0 +1,122 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::{segment_sum_by, sum_by};
+use crate::trapezoid::classify;
+use num_complex::Complex;
+
+/// Calculates the definite integral of complex samples over a real axis
+/// using the trapezoidal rule.
+///
+/// # Arguments
+/// * `y` - The array of complex function values.
+/// * `x` - The array of monotonic real abscissas, descending abscissas give the same
+///   result as ascending ones, like in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The approximate definite integral, or NaN in both parts if `y` has fewer than
+/// two samples, the lengths of `y` and `x` differ or `x` is not monotonic.
+pub fn trapezoid_complex_f64(y: &[Complex<f64>], x: &[f64]) -> Complex<f64> {
+    trapezoid_complex(y, x)
+}
+
+/// Calculates the definite integral of complex samples over a real axis
+/// using the trapezoidal rule.
+///
+/// # Arguments
+/// * `y` - The array of complex function values.
+/// * `x` - The array of monotonic real abscissas, descending abscissas give the same
+///   result as ascending ones, like in [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The approximate definite integral, or NaN in both parts if `y` has fewer than
+/// two samples, the lengths of `y` and `x` differ or `x` is not monotonic.
+pub fn trapezoid_complex_f32(y: &[Complex<f32>], x: &[f32]) -> Complex<f32> {
+    trapezoid_complex(y, x)
+}
+
+fn trapezoid_complex<T: TrapezSample>(y: &[Complex<T>], x: &[T]) -> Complex<T> {
+    let n = y.len();
+    if n < 2 || x.len() != n {
+        return Complex::new(T::nan(), T::nan());
+    }
+    let Ok(grid) = classify(x) else {
+        return Complex::new(T::nan(), T::nan());
+    };
+
+    // the real and imaginary parts are independent real integrals over the same grid
+    let integrate = |part: fn(&Complex<T>) -> T| match grid.step {
+        Some(h0) => {
+            let interior_sum = sum_by(1, n - 1, &|i| part(&y[i]));
+            h0 * fmla(part(&y[0]) + part(&y[n - 1]), T::HALF, interior_sum)
+        }
+        None => segment_sum_by(x, &|i| part(&y[i])),
+    };
+    let integral = Complex::new(integrate(|v| v.re), integrate(|v| v.im));
+    if grid.descending { -integral } else { integral }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_complex_exp() {
+        let n = 1000;
+        let x: Vec<f64> = (0..=n)
+            .map(|i| i as f64 * 2. * std::f64::consts::PI / n as f64)
+            .collect();
+        let y: Vec<Complex<f64>> = x.iter().map(|&x| Complex::new(0., x).exp()).collect();
+        let result = trapezoid_complex_f64(&y, &x);
+        assert!(result.norm() < 1e-12, "{result}");
+        // e^(ix) over [0, pi/2] on a non-uniform grid is 1 + i
+        let x: Vec<f64> = (0..=n)
+            .map(|i| (i as f64 / n as f64).powi(2) * std::f64::consts::FRAC_PI_2)
+            .collect();
+        let y: Vec<Complex<f64>> = x.iter().map(|&x| Complex::new(0., x).exp()).collect();
+        let result = trapezoid_complex_f64(&y, &x);
+        assert!((result - Complex::new(1., 1.)).norm() < 1e-5, "{result}");
+        let (rx, ry): (Vec<f64>, Vec<Complex<f64>>) = x.iter().rev().zip(y.iter().rev()).unzip();
+        assert!((trapezoid_complex_f64(&ry, &rx) - result).norm() < 1e-14);
+        // real samples give the real trapezoid rule
+        let re: Vec<f64> = y.iter().map(|v| v.re).collect();
+        let real: Vec<Complex<f64>> = re.iter().map(|&v| Complex::new(v, 0.)).collect();
+        assert_eq!(
+            trapezoid_complex_f64(&real, &x).re,
+            crate::trapezoid_f64(&re, &x)
+        );
+        let uniform: Vec<f64> = (0..=n).map(|i| i as f64 * 1e-3).collect();
+        let integral = trapezoid_complex_f64(&real, &uniform).re;
+        let expected = crate::trapezoid_f64(&re, &uniform);
+        assert!((integral - expected).abs() <= 1e-15 * expected.abs());
+        let wrong = trapezoid_complex_f64(&y[..3], &[0., 1., 0.5]);
+        assert!(wrong.re.is_nan() && wrong.im.is_nan());
+    }
+}
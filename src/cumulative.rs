@@ -0,0 +1,343 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+#[cfg(feature = "alloc")]
+use crate::mla::fmla;
+#[cfg(feature = "alloc")]
+use crate::simpson::simpson38;
+use crate::trapezoid::{add_segment, classify, validate};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use num_traits::AsPrimitive;
+
+#[cfg(feature = "alloc")]
+/// Cumulative trapezoidal integration.
+///
+/// Element `i` of the result is the signed integral from `x[0]` to `x[i]`, so for
+/// descending abscissas the running values have the opposite sign of
+/// [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of monotonic abscissas.
+/// * `initial` - Whether to start the output with the zero integral up to `x[0]`, giving
+///   `y.len()` elements instead of `y.len() - 1` (the SciPy default layout).
+///
+/// # Returns
+/// The running integral, or an empty vector if the input is invalid or `x` is not monotonic.
+pub fn cumtrapz_f64(y: &[f64], x: &[f64], initial: bool) -> Vec<f64> {
+    cumtrapz(y, x, initial)
+}
+
+#[cfg(feature = "alloc")]
+/// Cumulative trapezoidal integration.
+///
+/// Element `i` of the result is the signed integral from `x[0]` to `x[i]`, so for
+/// descending abscissas the running values have the opposite sign of
+/// [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of monotonic abscissas.
+/// * `initial` - Whether to start the output with the zero integral up to `x[0]`, giving
+///   `y.len()` elements instead of `y.len() - 1` (the SciPy default layout).
+///
+/// # Returns
+/// The running integral, or an empty vector if the input is invalid or `x` is not monotonic.
+pub fn cumtrapz_f32(y: &[f32], x: &[f32], initial: bool) -> Vec<f32> {
+    cumtrapz(y, x, initial)
+}
+
+#[cfg(feature = "alloc")]
+/// Cumulative Simpson integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Element `i` of the result is the integral from `x[0]` to `x[i]`, the first element
+/// is always zero. Even indices are the composite Simpson 1/3 rule, odd ones add the
+/// integral of the parabola through the panel over its first interval. With an even
+/// number of samples the last three intervals use the cubic through the last four
+/// samples, so the final element matches [simpson_even_f64](crate::simpson_even_f64).
+///
+/// # Returns
+/// The running integral, or an empty vector if `y` has fewer than 3 samples or `dx <= 0`.
+pub fn cumulative_simpson_even_f64(y: &[f64], dx: f64) -> Vec<f64> {
+    cumulative_simpson_even(y, dx)
+}
+
+#[cfg(feature = "alloc")]
+/// Cumulative Simpson integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Element `i` of the result is the integral from `x[0]` to `x[i]`, the first element
+/// is always zero. Even indices are the composite Simpson 1/3 rule, odd ones add the
+/// integral of the parabola through the panel over its first interval. With an even
+/// number of samples the last three intervals use the cubic through the last four
+/// samples, so the final element matches [simpson_even_f32](crate::simpson_even_f32).
+///
+/// # Returns
+/// The running integral, or an empty vector if `y` has fewer than 3 samples or `dx <= 0`.
+pub fn cumulative_simpson_even_f32(y: &[f32], dx: f32) -> Vec<f32> {
+    cumulative_simpson_even(y, dx)
+}
+
+/// Cumulative trapezoidal integration into a caller-provided buffer.
+///
+/// `out` must have either `y.len()` elements, in which case `out[0]` is set to zero
+/// and `out[i]` is the integral from `x[0]` to `x[i]`, or `y.len() - 1` elements,
+/// in which case the leading zero is omitted (the SciPy default layout). The integrals
+/// are signed, as in [cumtrapz_f64].
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::LengthMismatch] if `y` and `x` lengths differ.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+/// * [IntegrateError::OutputLength] if `out` has any other length.
+pub fn cumtrapz_into_f64(y: &[f64], x: &[f64], out: &mut [f64]) -> Result<(), IntegrateError> {
+    cumtrapz_into(y, x, out)
+}
+
+/// Cumulative trapezoidal integration into a caller-provided buffer.
+///
+/// `out` must have either `y.len()` elements, in which case `out[0]` is set to zero
+/// and `out[i]` is the integral from `x[0]` to `x[i]`, or `y.len() - 1` elements,
+/// in which case the leading zero is omitted (the SciPy default layout). The integrals
+/// are signed, as in [cumtrapz_f32].
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::LengthMismatch] if `y` and `x` lengths differ.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+/// * [IntegrateError::OutputLength] if `out` has any other length.
+pub fn cumtrapz_into_f32(y: &[f32], x: &[f32], out: &mut [f32]) -> Result<(), IntegrateError> {
+    cumtrapz_into(y, x, out)
+}
+
+/// Abscissa at which the running trapezoid integral from `x[0]` first reaches `target`.
+///
+/// Segment areas are accumulated as in [cumtrapz_into_f64] and the walk stops at the
+/// first segment whose running total crosses `target`. The crossing point inside that
+/// segment is found by linear interpolation of the running total, which is exact when
+/// the two samples bounding it are equal.
+///
+/// # Returns
+/// The interpolated crossing, `x[0]` for a zero target, or `None` if the running
+/// integral never reaches `target`, the input is invalid or `x` is not monotonic.
+pub fn trapezoid_until_f64(y: &[f64], x: &[f64], target: f64) -> Option<f64> {
+    trapezoid_until(y, x, target)
+}
+
+/// Abscissa at which the running trapezoid integral from `x[0]` first reaches `target`.
+///
+/// Segment areas are accumulated as in [cumtrapz_into_f32] and the walk stops at the
+/// first segment whose running total crosses `target`. The crossing point inside that
+/// segment is found by linear interpolation of the running total, which is exact when
+/// the two samples bounding it are equal.
+///
+/// # Returns
+/// The interpolated crossing, `x[0]` for a zero target, or `None` if the running
+/// integral never reaches `target`, the input is invalid or `x` is not monotonic.
+pub fn trapezoid_until_f32(y: &[f32], x: &[f32], target: f32) -> Option<f32> {
+    trapezoid_until(y, x, target)
+}
+
+fn trapezoid_until<T: TrapezSample>(y: &[T], x: &[T], target: T) -> Option<T> {
+    validate(y, x).ok()?;
+    classify(x).ok()?;
+    if target == T::zero() {
+        return Some(x[0]);
+    }
+    let mut running = T::zero();
+    for (y, x) in y.windows(2).zip(x.windows(2)) {
+        let next = add_segment(running, y, x);
+        let before = running - target;
+        let after = next - target;
+        if after == T::zero() || (before < T::zero()) != (after < T::zero()) {
+            let t = (target - running) / (next - running);
+            return Some(x[0] + t * (x[1] - x[0]));
+        }
+        running = next;
+    }
+    None
+}
+
+#[cfg(feature = "alloc")]
+fn cumtrapz<T: TrapezSample>(y: &[T], x: &[T], initial: bool) -> Vec<T> {
+    let len = if initial {
+        y.len()
+    } else {
+        y.len().saturating_sub(1)
+    };
+    let mut out = vec![T::zero(); len];
+    match cumtrapz_into(y, x, &mut out) {
+        Ok(_) => out,
+        Err(_) => Vec::new(),
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn cumulative_simpson_even<T: TrapezSample>(y: &[T], dx: T) -> Vec<T>
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 3 || dx <= T::zero() {
+        return Vec::new();
+    }
+    let third = dx / 3f64.as_();
+    let twelfth = dx / 12f64.as_();
+    // panels of two intervals, an even sample count leaves three for the cubic tail
+    let panel_end = if n.is_multiple_of(2) { n - 4 } else { n - 1 };
+    let mut out = vec![T::zero(); n];
+    let mut i = 0;
+    while i < panel_end {
+        let (y0, y1, y2) = (y[i], y[i + 1], y[i + 2]);
+        out[i + 1] = out[i] + twelfth * fmla(8f64.as_(), y1, fmla(5f64.as_(), y0, -y2));
+        out[i + 2] = out[i] + third * fmla(4f64.as_(), y1, y0 + y2);
+        i += 2;
+    }
+    if n.is_multiple_of(2) {
+        let t = &y[n - 4..];
+        let base = out[n - 4];
+        let first = fmla(
+            9f64.as_(),
+            t[0],
+            fmla(19f64.as_(), t[1], t[3] - 5f64.as_() * t[2]),
+        );
+        out[n - 3] = base + dx / 24f64.as_() * first;
+        out[n - 2] = base + third * fmla(4f64.as_(), t[1], t[0] + t[2]);
+        out[n - 1] = base + simpson38(t, dx);
+    }
+    out
+}
+
+fn cumtrapz_into<T: TrapezSample>(y: &[T], x: &[T], out: &mut [T]) -> Result<(), IntegrateError> {
+    validate(y, x)?;
+    classify(x)?;
+    let n = y.len();
+    let dst = if out.len() == n {
+        out[0] = T::zero();
+        &mut out[1..]
+    } else if out.len() == n - 1 {
+        out
+    } else {
+        return Err(IntegrateError::OutputLength {
+            expected: n,
+            actual: out.len(),
+        });
+    };
+
+    let mut running = T::zero();
+    for ((dst, y), x) in dst.iter_mut().zip(y.windows(2)).zip(x.windows(2)) {
+        running = add_segment(running, y, x);
+        *dst = running;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cumtrapz() {
+        use crate::trapezoid_f64;
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let result = cumtrapz_f64(&y, &x, true);
+        assert_eq!(result, vec![0., 5.5, 12.5, 17.5, 22.5, 30.5]);
+        assert_eq!(*result.last().unwrap(), trapezoid_f64(&y, &x));
+        assert_eq!(cumtrapz_f64(&y, &x, false), result[1..]);
+        assert!(cumtrapz_f64(&y, &x[1..], true).is_empty());
+        assert!(cumtrapz_f64(&[1., 2., 3.], &[0., 2., 1.], true).is_empty());
+        // descending abscissas integrate from x[0] towards smaller values
+        let result = cumtrapz_f64(&[1., 2., 3.], &[2., 1., 0.], false);
+        assert_eq!(result, vec![-1.5, -4.]);
+        assert_eq!(result[1], -trapezoid_f64(&[1., 2., 3.], &[2., 1., 0.]));
+    }
+
+    #[test]
+    fn test_trapezoid_until() {
+        // constant density 2 on [0, 5], the running integral is 2 * x
+        let x = [0., 1., 2., 3., 4., 5.];
+        let y = [2.; 6];
+        assert_eq!(trapezoid_until_f64(&y, &x, 5.), Some(2.5));
+        assert_eq!(trapezoid_until_f64(&y, &x, 4.), Some(2.));
+        assert_eq!(trapezoid_until_f64(&y, &x, 0.), Some(0.));
+        assert_eq!(trapezoid_until_f64(&y, &x, 10.5), None);
+        assert_eq!(trapezoid_until_f32(&[-1., -1.], &[0., 4.], -2.), Some(2.));
+        assert_eq!(trapezoid_until_f64(&y[1..], &x, 1.), None);
+        assert_eq!(trapezoid_until_f64(&y, &[0., 1., 2., 1., 4., 5.], 3.), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cumulative_simpson_even() {
+        use crate::simpson_even_f64;
+        for n in [3, 4, 7, 10] {
+            let y: Vec<f64> = (0..n).map(|i| (i as f64 * 0.3).sin()).collect();
+            let result = cumulative_simpson_even_f64(&y, 0.3);
+            assert_eq!(result.len(), n);
+            assert_eq!(result[0], 0.);
+            let last = *result.last().unwrap();
+            assert!((last - simpson_even_f64(&y, 0.3)).abs() < 1e-14, "{n}");
+            // running values track 1 - cos(x)
+            for (i, v) in result.iter().enumerate() {
+                assert!((v - (1. - (i as f64 * 0.3).cos())).abs() < 1e-3, "{n} {i}");
+            }
+        }
+        assert!(cumulative_simpson_even_f64(&[1., 2.], 0.3).is_empty());
+    }
+
+    #[test]
+    fn test_cumtrapz_into() {
+        let y = [5f32, 6., 1., 4., 6., 2.];
+        let x = [1f32, 2., 4., 6., 7., 9.];
+        let mut out = [0f32; 5];
+        cumtrapz_into_f32(&y, &x, &mut out).unwrap();
+        assert_eq!(out, [5.5, 12.5, 17.5, 22.5, 30.5]);
+        let mut out = [0f32; 4];
+        assert_eq!(
+            cumtrapz_into_f32(&y, &x, &mut out),
+            Err(IntegrateError::OutputLength {
+                expected: 6,
+                actual: 4
+            })
+        );
+        let mut out = [0f32; 3];
+        assert_eq!(
+            cumtrapz_into_f32(&[1., 2., 3.], &[0., 2., 1.], &mut out),
+            Err(IntegrateError::NonMonotonic { index: 1 })
+        );
+    }
+}
@@ -0,0 +1,112 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use crate::sum::sum;
+use crate::trapezoid::trapezoid_even;
+use alloc::collections::VecDeque;
+
+/// Trapezoidal integration for evenly spaced samples held in a ring buffer.
+/// `buf` = function values
+/// `dx` = spacing between x-values
+///
+/// Integrates the two halves returned by [VecDeque::as_slices] in place, including the
+/// segment joining them, so no [VecDeque::make_contiguous] is needed.
+/// Returns NaN for fewer than 2 samples or a non-positive `dx`, like [trapezoid_even_f64](crate::trapezoid_even_f64).
+pub fn trapezoid_even_deque_f64(buf: &VecDeque<f64>, dx: f64) -> f64 {
+    trapezoid_even_deque(buf, dx)
+}
+
+/// Trapezoidal integration for evenly spaced samples held in a ring buffer.
+/// `buf` = function values
+/// `dx` = spacing between x-values
+///
+/// Integrates the two halves returned by [VecDeque::as_slices] in place, including the
+/// segment joining them, so no [VecDeque::make_contiguous] is needed.
+/// Returns NaN for fewer than 2 samples or a non-positive `dx`, like [trapezoid_even_f32](crate::trapezoid_even_f32).
+pub fn trapezoid_even_deque_f32(buf: &VecDeque<f32>, dx: f32) -> f32 {
+    trapezoid_even_deque(buf, dx)
+}
+
+fn trapezoid_even_deque<T: TrapezSample>(buf: &VecDeque<T>, dx: T) -> T {
+    try_trapezoid_even_deque(buf, dx).unwrap_or(T::nan())
+}
+
+fn try_trapezoid_even_deque<T: TrapezSample>(
+    buf: &VecDeque<T>,
+    dx: T,
+) -> Result<T, IntegrateError> {
+    let (head, tail) = buf.as_slices();
+    if head.is_empty() || tail.is_empty() {
+        return Ok(trapezoid_even(
+            if tail.is_empty() { head } else { tail },
+            dx,
+        ));
+    }
+    if !dx.is_finite() {
+        return Err(IntegrateError::NonFiniteSpacing);
+    }
+    if dx <= T::zero() {
+        return Err(IntegrateError::NonPositiveSpacing);
+    }
+    // every sample but the first of `head` and the last of `tail` is interior,
+    // the segment straddling the wrap is covered by `head`'s last and `tail`'s first
+    let interior = sum(&head[1..]) + sum(&tail[..tail.len() - 1]);
+    Ok(dx * fmla(T::HALF, head[0] + tail[tail.len() - 1], interior))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_even_deque() {
+        let values = [5., 6., 1., 4., 6., 2., 3.];
+        let mut buf = VecDeque::with_capacity(values.len());
+        // push to the front so the buffer wraps around its storage
+        for &v in values[..3].iter().rev() {
+            buf.push_front(v);
+        }
+        buf.extend(&values[3..]);
+        let (head, tail) = buf.as_slices();
+        assert!(!head.is_empty() && !tail.is_empty());
+        assert_eq!(
+            trapezoid_even_deque_f64(&buf, 0.5),
+            crate::trapezoid_even_f64(&values, 0.5)
+        );
+        let contiguous: VecDeque<f64> = values.iter().copied().collect();
+        assert_eq!(
+            trapezoid_even_deque_f64(&contiguous, 0.5),
+            crate::trapezoid_even_f64(&values, 0.5)
+        );
+        assert!(trapezoid_even_deque_f64(&VecDeque::new(), 1.).is_nan());
+        assert!(trapezoid_even_deque_f32(&VecDeque::from([1.]), 1.).is_nan());
+    }
+}
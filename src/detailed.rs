@@ -0,0 +1,162 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::trapezoid::{classify, integrate_signed, validate};
+
+/// Outcome of a trapezoidal integration together with how it was computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrapezoidResult<T> {
+    /// The approximate definite integral, NaN if the input was invalid.
+    pub value: T,
+    /// Number of trapezoid segments, `y.len() - 1`, zero for invalid input.
+    pub segments: usize,
+    /// Whether the abscissas were detected as uniformly spaced.
+    pub uniform: bool,
+    /// Width of the integration interval `|x[n - 1] - x[0]|`, NaN for invalid input.
+    pub span: T,
+}
+
+/// [trapezoid_f64](crate::trapezoid_f64) reporting how the integral was computed.
+pub fn trapezoid_detailed_f64(y: &[f64], x: &[f64]) -> TrapezoidResult<f64> {
+    trapezoid_detailed(y, x)
+}
+
+/// [trapezoid_f32](crate::trapezoid_f32) reporting how the integral was computed.
+pub fn trapezoid_detailed_f32(y: &[f32], x: &[f32]) -> TrapezoidResult<f32> {
+    trapezoid_detailed(y, x)
+}
+
+/// [trapezoid_f64](crate::trapezoid_f64) together with its largest segment.
+///
+/// Returns `(integral, index, area)` where segment `index` spans `x[index]..x[index + 1]`
+/// and has the largest absolute area, useful to flag spikes. Ties keep the first segment.
+/// Segment areas are accumulated in a single pass, invalid input gives `(NaN, 0, NaN)`.
+pub fn trapezoid_with_max_segment_f64(y: &[f64], x: &[f64]) -> (f64, usize, f64) {
+    trapezoid_with_max_segment(y, x)
+}
+
+/// [trapezoid_f32](crate::trapezoid_f32) together with its largest segment.
+///
+/// Returns `(integral, index, area)` where segment `index` spans `x[index]..x[index + 1]`
+/// and has the largest absolute area, useful to flag spikes. Ties keep the first segment.
+/// Segment areas are accumulated in a single pass, invalid input gives `(NaN, 0, NaN)`.
+pub fn trapezoid_with_max_segment_f32(y: &[f32], x: &[f32]) -> (f32, usize, f32) {
+    trapezoid_with_max_segment(y, x)
+}
+
+fn trapezoid_detailed<T: TrapezSample>(y: &[T], x: &[T]) -> TrapezoidResult<T> {
+    let invalid = TrapezoidResult {
+        value: T::nan(),
+        segments: 0,
+        uniform: false,
+        span: T::nan(),
+    };
+    if validate(y, x).is_err() {
+        return invalid;
+    }
+    let Ok(grid) = classify(x) else {
+        return invalid;
+    };
+    let integral = integrate_signed(y, x, grid.step);
+    TrapezoidResult {
+        value: if grid.descending { -integral } else { integral },
+        segments: y.len() - 1,
+        uniform: grid.step.is_some(),
+        span: (x[x.len() - 1] - x[0]).abs(),
+    }
+}
+
+fn trapezoid_with_max_segment<T: TrapezSample>(y: &[T], x: &[T]) -> (T, usize, T) {
+    if validate(y, x).is_err() {
+        return (T::nan(), 0, T::nan());
+    }
+    let Ok(grid) = classify(x) else {
+        return (T::nan(), 0, T::nan());
+    };
+    let sign = if grid.descending { -T::one() } else { T::one() };
+    let mut total = T::zero();
+    let (mut index, mut peak) = (0, T::zero());
+    for (i, (y, x)) in y.windows(2).zip(x.windows(2)).enumerate() {
+        let area = sign * T::HALF * (y[0] + y[1]) * (x[1] - x[0]);
+        total += area;
+        if i == 0 || area.abs() > peak.abs() {
+            (index, peak) = (i, area);
+        }
+    }
+    (total, index, peak)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_detailed() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let result = trapezoid_detailed_f64(&y, &[1., 2., 4., 6., 7., 9.]);
+        assert_eq!(
+            result,
+            TrapezoidResult {
+                value: 30.5,
+                segments: 5,
+                uniform: false,
+                span: 8.
+            }
+        );
+        let result = trapezoid_detailed_f64(&y, &[2.5, 2., 1.5, 1., 0.5, 0.]);
+        assert!(result.uniform);
+        assert_eq!(result.value, 10.25);
+        assert_eq!(result.span, 2.5);
+        assert!(trapezoid_detailed_f32(&[1.], &[1.]).value.is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_with_max_segment() {
+        let y = [1., 1.2, 0.9, 40., 1.1, 1.];
+        let x = [0., 1., 2., 2.5, 3., 4.];
+        let (total, index, area) = trapezoid_with_max_segment_f64(&y, &x);
+        assert!((total - crate::trapezoid_f64(&y, &x)).abs() < 1e-12);
+        // the spike at x = 2.5 borders segments 2 and 3, the narrower
+        // segment 3 carries more area because it rises from a larger value
+        assert_eq!((index, area), (3, 0.25 * 41.1));
+        let rev = trapezoid_with_max_segment_f32(&[3., 1., 1.], &[2., 1., 0.]);
+        assert_eq!(rev, (3., 0, 2.));
+        assert!(trapezoid_with_max_segment_f64(&[1.], &[1.]).0.is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_trapezoid_result_serde() {
+        let result = trapezoid_detailed_f64(&[1., 2., 3.], &[0., 1., 2.]);
+        let json = serde_json::to_string(&result).unwrap();
+        let back: TrapezoidResult<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, result);
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{add_segment, classify, trapezoid, trapezoid_even};
+
+/// Double integral `∫∫ z dx dy` over an evenly spaced rectangular grid.
+///
+/// `z` is row-major with `ny` rows of `nx` samples, row `j` lies at `y = j * dy`
+/// and column `i` at `x = i * dx`. Every row is integrated with the trapezoid rule
+/// and the row integrals are then integrated along `y`, no intermediate buffer is needed.
+///
+/// # Returns
+/// The approximate double integral, or NaN if `z.len() != nx * ny`,
+/// `nx` or `ny` is less than 2, or `dx`, `dy` are not positive.
+pub fn trapezoid_2d_f64(z: &[f64], nx: usize, ny: usize, dx: f64, dy: f64) -> f64 {
+    trapezoid_2d(z, nx, ny, dx, dy)
+}
+
+/// Double integral `∫∫ z dx dy` over an evenly spaced rectangular grid.
+///
+/// `z` is row-major with `ny` rows of `nx` samples, row `j` lies at `y = j * dy`
+/// and column `i` at `x = i * dx`. Every row is integrated with the trapezoid rule
+/// and the row integrals are then integrated along `y`, no intermediate buffer is needed.
+///
+/// # Returns
+/// The approximate double integral, or NaN if `z.len() != nx * ny`,
+/// `nx` or `ny` is less than 2, or `dx`, `dy` are not positive.
+pub fn trapezoid_2d_f32(z: &[f32], nx: usize, ny: usize, dx: f32, dy: f32) -> f32 {
+    trapezoid_2d(z, nx, ny, dx, dy)
+}
+
+/// Double integral `∫∫ z dx dy` over a rectangular grid with arbitrary spacing.
+///
+/// `z` is row-major with one row of `x.len()` samples for every element of `y`.
+/// Every row is integrated with [trapezoid_f64](crate::trapezoid_f64) and the row
+/// integrals are then integrated along `y` with the same trapezoid segments.
+/// Both `x` and `y` must be monotonic, descending axes follow the rules of the 1D routine.
+///
+/// # Returns
+/// The approximate double integral, or NaN if `z.len() != x.len() * y.len()`,
+/// either axis has fewer than two samples, or is not monotonic.
+pub fn trapezoid_2d_nonuniform_f64(z: &[f64], x: &[f64], y: &[f64]) -> f64 {
+    trapezoid_2d_nonuniform(z, x, y)
+}
+
+/// Double integral `∫∫ z dx dy` over a rectangular grid with arbitrary spacing.
+///
+/// `z` is row-major with one row of `x.len()` samples for every element of `y`.
+/// Every row is integrated with [trapezoid_f32](crate::trapezoid_f32) and the row
+/// integrals are then integrated along `y` with the same trapezoid segments.
+/// Both `x` and `y` must be monotonic, descending axes follow the rules of the 1D routine.
+///
+/// # Returns
+/// The approximate double integral, or NaN if `z.len() != x.len() * y.len()`,
+/// either axis has fewer than two samples, or is not monotonic.
+pub fn trapezoid_2d_nonuniform_f32(z: &[f32], x: &[f32], y: &[f32]) -> f32 {
+    trapezoid_2d_nonuniform(z, x, y)
+}
+
+fn trapezoid_2d<T: TrapezSample>(z: &[T], nx: usize, ny: usize, dx: T, dy: T) -> T {
+    if nx.checked_mul(ny) != Some(z.len()) || ny < 2 || dy <= T::zero() {
+        return T::nan();
+    }
+    // nx and dx are validated by every row integral
+    let row = |j: usize| trapezoid_even(&z[j * nx..(j + 1) * nx], dx);
+    let interior = pairwise_fold(1, ny - 1, &|acc, j| acc + row(j));
+    dy * fmla(row(0) + row(ny - 1), T::HALF, interior)
+}
+
+fn trapezoid_2d_nonuniform<T: TrapezSample>(z: &[T], x: &[T], y: &[T]) -> T {
+    let nx = x.len();
+    if nx.checked_mul(y.len()) != Some(z.len()) || nx < 2 || y.len() < 2 {
+        return T::nan();
+    }
+    let Ok(grid) = classify(y) else {
+        return T::nan();
+    };
+    // x is validated by every row integral
+    let mut rows = z.chunks_exact(nx).map(|row| trapezoid(row, x));
+    let mut prev = rows.next().unwrap_or(T::nan());
+    let mut integral = T::zero();
+    for (row, y) in rows.zip(y.windows(2)) {
+        integral = add_segment(integral, &[prev, row], y);
+        prev = row;
+    }
+    if grid.descending { -integral } else { integral }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_2d() {
+        let z = [2.5; 15];
+        assert_eq!(trapezoid_2d_f64(&z, 5, 3, 0.5, 0.25), 2.5);
+        assert!(trapezoid_2d_f64(&z, 4, 3, 0.5, 0.25).is_nan());
+        assert!(trapezoid_2d_f64(&z, 15, 1, 0.5, 0.25).is_nan());
+
+        // x * y is bilinear, so every refinement is exact up to rounding
+        for n in [3, 9, 33] {
+            let h = 1. / (n - 1) as f64;
+            let z: Vec<f64> = (0..n * n)
+                .map(|k| (k % n) as f64 * h * (k / n) as f64 * h)
+                .collect();
+            let result = trapezoid_2d_f64(&z, n, n, h, h);
+            assert!((result - 0.25).abs() < 1e-14, "{n}: {result}");
+        }
+    }
+
+    #[test]
+    fn test_trapezoid_2d_nonuniform() {
+        use crate::trapezoid_f64;
+        let x: [f64; 5] = [0., 0.1, 0.5, 0.6, 1.2];
+        let y = [-1., 0., 0.25, 2.];
+        let z: Vec<f64> = y
+            .iter()
+            .flat_map(|&y| x.iter().map(move |&x| (x * y).sin() + x))
+            .collect();
+        let rows: Vec<f64> = z
+            .chunks(x.len())
+            .map(|row| trapezoid_f64(row, &x))
+            .collect();
+        let nested = trapezoid_f64(&rows, &y);
+        assert_eq!(trapezoid_2d_nonuniform_f64(&z, &x, &y), nested);
+        assert!(trapezoid_2d_nonuniform_f64(&z[1..], &x, &y).is_nan());
+        assert!(trapezoid_2d_nonuniform_f64(&z, &x, &[-1., 0., -0.25, 2.]).is_nan());
+        assert!(trapezoid_2d_nonuniform_f64(&[], &[], &[0., 1.]).is_nan());
+        assert!(trapezoid_2d_nonuniform_f64(&[1., 2.], &[0.5], &[0., 1.]).is_nan());
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+/// Unevaluated sum `hi + lo` with `|lo| <= ulp(hi) / 2`, about 106 bits of precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    const ZERO: Self = Self { hi: 0., lo: 0. };
+
+    fn from_sum(a: f64, b: f64) -> Self {
+        let (hi, lo) = two_sum(a, b);
+        Self { hi, lo }
+    }
+
+    fn add(self, other: Self) -> Self {
+        let (s, e) = two_sum(self.hi, other.hi);
+        let (hi, lo) = fast_two_sum(s, e + self.lo + other.lo);
+        Self { hi, lo }
+    }
+
+    fn add_f64(self, v: f64) -> Self {
+        let (s, e) = two_sum(self.hi, v);
+        let (hi, lo) = fast_two_sum(s, e + self.lo);
+        Self { hi, lo }
+    }
+
+    fn mul_f64(self, v: f64) -> Self {
+        let (p, e) = two_prod(self.hi, v);
+        let (hi, lo) = fast_two_sum(p, e + self.lo * v);
+        Self { hi, lo }
+    }
+}
+
+/// Knuth's error-free sum, `a + b == s + e` exactly.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Error-free sum for `|a| >= |b|`.
+#[inline]
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// Dekker's splitting into two 26 bit halves.
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c = 134217729. * a; // 2^27 + 1
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// Dekker's error-free product, `a * b == p + e` exactly barring over- and underflow.
+/// Plain arithmetic keeps it independent of hardware fused multiply-add.
+#[inline]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// Trapezoidal integration for evenly spaced samples with double-double accumulation.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// The interior sum, the endpoint terms and the multiplication by `dx` are carried out
+/// in double-double arithmetic with about 106 significant bits, only the final result
+/// is rounded to `f64`. The sum is then accurate to the last bit for all but the most
+/// cancelling inputs, at several times the cost of [trapezoid_even_f64](crate::trapezoid_even_f64).
+///
+/// Returns NaN if `y` has fewer than two samples or `dx` is not positive and finite.
+pub fn trapezoid_even_dd_f64(y: &[f64], dx: f64) -> f64 {
+    let n = y.len();
+    if n < 2 || !dx.is_finite() || dx <= 0. {
+        return f64::NAN;
+    }
+    let interior = y[1..n - 1]
+        .iter()
+        .fold(DoubleDouble::ZERO, |acc, &v| acc.add_f64(v));
+    // halving is exact, so the endpoints enter the sum without rounding
+    let ends = DoubleDouble::from_sum(y[0] * 0.5, y[n - 1] * 0.5);
+    let total = interior.add(ends).mul_f64(dx);
+    total.hi + total.lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_even_dd() {
+        // multiples of 2^-30 around 1e6, the exact sum is an integer count of 2^-30
+        let n = 10_000_000;
+        let unit = f64::powi(2., -30);
+        let y: Vec<f64> = (0..n)
+            .map(|i| 1e6 + ((i * 37) % 1000) as f64 * unit)
+            .collect();
+        let exact: i128 = y
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                let units = (v / unit) as i128;
+                if i == 0 || i == n - 1 {
+                    units
+                } else {
+                    2 * units
+                }
+            })
+            .sum();
+        let reference = exact as f64 * unit * 0.5;
+        let naive = y[1..n - 1]
+            .iter()
+            .fold(0.5 * (y[0] + y[n - 1]), |acc, &v| acc + v);
+        assert_eq!(trapezoid_even_dd_f64(&y, 1.), reference);
+        assert!((naive - reference).abs() > 1e-3, "{naive} vs {reference}");
+        assert!(trapezoid_even_dd_f64(&y[..1], 1.).is_nan());
+        assert!(trapezoid_even_dd_f64(&y, f64::NAN).is_nan());
+    }
+}
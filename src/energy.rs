@@ -0,0 +1,124 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{classify, validate};
+
+/// Signal energy `∫ y^2 dx` by the trapezoidal rule over the squared samples.
+///
+/// Every segment contributes `0.5 * dx * (y[i]^2 + y[i + 1]^2)`, no squared copy of `y`
+/// is made. This is not the integral of the square of the linear interpolant, which is
+/// smaller by `dx * (y[i + 1] - y[i])^2 / 6` per segment, see [trapezoid_energy_exact_f64].
+/// `x` follows the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The energy, or NaN if the lengths differ, fewer than two samples are given
+/// or `x` is not monotonic.
+pub fn trapezoid_energy_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_energy(y, x, false)
+}
+
+/// Signal energy `∫ y^2 dx` by the trapezoidal rule over the squared samples.
+///
+/// Every segment contributes `0.5 * dx * (y[i]^2 + y[i + 1]^2)`, no squared copy of `y`
+/// is made. This is not the integral of the square of the linear interpolant, which is
+/// smaller by `dx * (y[i + 1] - y[i])^2 / 6` per segment, see [trapezoid_energy_exact_f32].
+/// `x` follows the rules of [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The energy, or NaN if the lengths differ, fewer than two samples are given
+/// or `x` is not monotonic.
+pub fn trapezoid_energy_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_energy(y, x, false)
+}
+
+/// Exact integral of the square of the piecewise linear interpolant of `y`.
+///
+/// Every segment contributes `dx * (y[i]^2 + y[i] * y[i + 1] + y[i + 1]^2) / 3`,
+/// which never exceeds the [trapezoid_energy_f64] estimate.
+///
+/// # Returns
+/// The energy, or NaN under the same conditions as [trapezoid_energy_f64].
+pub fn trapezoid_energy_exact_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_energy(y, x, true)
+}
+
+/// Exact integral of the square of the piecewise linear interpolant of `y`.
+///
+/// Every segment contributes `dx * (y[i]^2 + y[i] * y[i + 1] + y[i + 1]^2) / 3`,
+/// which never exceeds the [trapezoid_energy_f32] estimate.
+///
+/// # Returns
+/// The energy, or NaN under the same conditions as [trapezoid_energy_f32].
+pub fn trapezoid_energy_exact_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_energy(y, x, true)
+}
+
+fn trapezoid_energy<T: TrapezSample>(y: &[T], x: &[T], exact_quadratic: bool) -> T {
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    let energy = if exact_quadratic {
+        let third = T::one() / (T::one() + T::one() + T::one());
+        pairwise_fold(0, y.len() - 1, &|acc, i| {
+            let (a, b) = (y[i], y[i + 1]);
+            fmla((x[i + 1] - x[i]) * third, fmla(a, a + b, b * b), acc)
+        })
+    } else {
+        pairwise_fold(0, y.len() - 1, &|acc, i| {
+            let (a, b) = (y[i], y[i + 1]);
+            fmla((x[i + 1] - x[i]) * T::HALF, fmla(a, a, b * b), acc)
+        })
+    };
+    if grid.descending { -energy } else { energy }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_energy() {
+        let x = [1., 1.5, 3., 4., 6.];
+        let c = [-3.; 5];
+        assert_eq!(trapezoid_energy_f64(&c, &x), 45.);
+        assert_eq!(trapezoid_energy_exact_f64(&c, &x), 45.);
+
+        // y = x on [0, 1], the interpolant is exact and its square integrates to 1/3
+        let x = [0., 0.5, 1.];
+        assert_eq!(trapezoid_energy_f64(&x, &x), 0.375);
+        assert!((trapezoid_energy_exact_f64(&x, &x) - 1. / 3.).abs() < 1e-15);
+        assert_eq!(trapezoid_energy_f32(&[2., 2.], &[1., 0.]), 4.);
+        assert!(trapezoid_energy_exact_f32(&[1.], &[0.]).is_nan());
+    }
+}
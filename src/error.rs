@@ -0,0 +1,96 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::fmt::{Display, Formatter};
+
+/// Errors reported by the checked (`try_*`) integration routines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntegrateError {
+    /// Fewer than two samples were provided.
+    TooFewPoints,
+    /// `y` and `x` have different lengths.
+    LengthMismatch { y: usize, x: usize },
+    /// Sample spacing is zero or negative.
+    NonPositiveSpacing,
+    /// Abscissas are not monotonic, `index` is the first interval
+    /// `x[index]..x[index + 1]` running against the established direction.
+    NonMonotonic { index: usize },
+    /// An adaptive routine hit its refinement limit before reaching the requested tolerance.
+    NotConverged,
+    /// Caller-provided output buffer has the wrong length.
+    OutputLength { expected: usize, actual: usize },
+    /// The samples are finite but the accumulated integral is not.
+    Overflow,
+    /// `x[index + 1] == x[index]` while duplicates are rejected.
+    DuplicateAbscissa { index: usize },
+    /// Sample spacing is NaN or infinite.
+    NonFiniteSpacing,
+    /// `y[index]` is NaN or infinite.
+    NonFiniteSample { index: usize },
+    /// `y[index]` is infinite, the integrand diverges at that abscissa.
+    Divergent { index: usize },
+    /// The selected rule cannot integrate this sample count or spacing,
+    /// e.g. Boole's rule on a non-uniform grid.
+    UnsupportedGrid,
+}
+
+impl Display for IntegrateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IntegrateError::TooFewPoints => f.write_str("at least two samples are required"),
+            IntegrateError::LengthMismatch { y, x } => {
+                write!(f, "length mismatch: y has {y} samples, x has {x}")
+            }
+            IntegrateError::NonPositiveSpacing => f.write_str("sample spacing must be positive"),
+            IntegrateError::NonMonotonic { index } => {
+                write!(f, "abscissas are not monotonic at index {index}")
+            }
+            IntegrateError::NotConverged => f.write_str("integration did not converge"),
+            IntegrateError::OutputLength { expected, actual } => {
+                write!(f, "output buffer has length {actual}, expected {expected}")
+            }
+            IntegrateError::Overflow => f.write_str("accumulated integral overflowed"),
+            IntegrateError::DuplicateAbscissa { index } => {
+                write!(f, "abscissas at index {index} and {} are equal", index + 1)
+            }
+            IntegrateError::NonFiniteSpacing => f.write_str("sample spacing must be finite"),
+            IntegrateError::NonFiniteSample { index } => {
+                write!(f, "sample at index {index} is not finite")
+            }
+            IntegrateError::Divergent { index } => {
+                write!(f, "integrand diverges at sample {index}")
+            }
+            IntegrateError::UnsupportedGrid => {
+                f.write_str("the integration rule does not support this grid")
+            }
+        }
+    }
+}
+
+impl core::error::Error for IntegrateError {}
@@ -0,0 +1,199 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::simpson::simpson_even;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::trapezoid_even;
+use num_traits::AsPrimitive;
+
+/// Trapezoidal rule for evenly spaced samples together with an error estimate.
+///
+/// The composite trapezoid error is `-(b - a) * dx^2 * y''(ξ) / 12`, here `y''` is
+/// approximated by the discrete second differences `y[i - 1] - 2 y[i] + y[i + 1]`,
+/// giving `dx / 12 * Σ |Δ²y|`. The estimate is heuristic: it is a magnitude, not a
+/// strict bound, and it is only meaningful when `y` is smooth and resolved by the grid.
+///
+/// # Returns
+/// `(integral, error)`. Both are NaN if the input is invalid (see [trapezoid_even_f64](crate::trapezoid_even_f64)),
+/// the error is NaN when there are only two samples and no curvature is available.
+pub fn trapezoid_with_error_even_f64(y: &[f64], dx: f64) -> (f64, f64) {
+    trapezoid_with_error_even(y, dx)
+}
+
+/// Trapezoidal rule for evenly spaced samples together with an error estimate.
+///
+/// The composite trapezoid error is `-(b - a) * dx^2 * y''(ξ) / 12`, here `y''` is
+/// approximated by the discrete second differences `y[i - 1] - 2 y[i] + y[i + 1]`,
+/// giving `dx / 12 * Σ |Δ²y|`. The estimate is heuristic: it is a magnitude, not a
+/// strict bound, and it is only meaningful when `y` is smooth and resolved by the grid.
+///
+/// # Returns
+/// `(integral, error)`. Both are NaN if the input is invalid (see [trapezoid_even_f32](crate::trapezoid_even_f32)),
+/// the error is NaN when there are only two samples and no curvature is available.
+pub fn trapezoid_with_error_even_f32(y: &[f32], dx: f32) -> (f32, f32) {
+    trapezoid_with_error_even(y, dx)
+}
+
+fn trapezoid_with_error_even<T: TrapezSample>(y: &[T], dx: T) -> (T, T)
+where
+    f64: AsPrimitive<T>,
+{
+    let integral = trapezoid_even(y, dx);
+    if integral.is_nan() || y.len() < 3 {
+        return (integral, T::nan());
+    }
+    let curvature = pairwise_fold(1, y.len() - 1, &|acc, i| {
+        acc + (y[i - 1] - (y[i] + y[i]) + y[i + 1]).abs()
+    });
+    (integral, dx * (1. / 12f64).as_() * curvature)
+}
+
+/// Discrepancy `|trapezoid - simpson|` between the two rules on evenly spaced samples.
+///
+/// Simpson's rule converges two orders faster, so for a well resolved, smooth `y` the
+/// difference is close to the trapezoid discretization error and shrinks as `dx^2`.
+/// A large discrepancy relative to the integral means the function is under-sampled
+/// and neither estimate can be trusted.
+///
+/// Returns NaN if `y` has fewer than 3 samples or `dx <= 0`.
+pub fn method_discrepancy_even_f64(y: &[f64], dx: f64) -> f64 {
+    method_discrepancy_even(y, dx)
+}
+
+/// Discrepancy `|trapezoid - simpson|` between the two rules on evenly spaced samples.
+///
+/// Simpson's rule converges two orders faster, so for a well resolved, smooth `y` the
+/// difference is close to the trapezoid discretization error and shrinks as `dx^2`.
+/// A large discrepancy relative to the integral means the function is under-sampled
+/// and neither estimate can be trusted.
+///
+/// Returns NaN if `y` has fewer than 3 samples or `dx <= 0`.
+pub fn method_discrepancy_even_f32(y: &[f32], dx: f32) -> f32 {
+    method_discrepancy_even(y, dx)
+}
+
+fn method_discrepancy_even<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    (trapezoid_even(y, dx) - simpson_even(y, dx)).abs()
+}
+
+/// Trapezoidal rule for evenly spaced samples together with its propagated uncertainty.
+///
+/// `sigma[i]` is the standard deviation of the independent noise on `y[i]`. The
+/// integral is the linear combination `Σ w_i y_i` with the trapezoid weights
+/// `w_i = dx` and `dx / 2` at the endpoints, so its standard deviation is
+/// `sqrt(Σ (w_i sigma_i)^2)`. Correlated noise is not accounted for.
+///
+/// # Returns
+/// `(integral, std_dev)`. Both are NaN if `sigma.len() != y.len()` or the input is
+/// invalid (see [trapezoid_even_f64](crate::trapezoid_even_f64)).
+pub fn trapezoid_even_with_sigma_f64(y: &[f64], dx: f64, sigma: &[f64]) -> (f64, f64) {
+    trapezoid_even_with_sigma(y, dx, sigma)
+}
+
+/// Trapezoidal rule for evenly spaced samples together with its propagated uncertainty.
+///
+/// `sigma[i]` is the standard deviation of the independent noise on `y[i]`. The
+/// integral is the linear combination `Σ w_i y_i` with the trapezoid weights
+/// `w_i = dx` and `dx / 2` at the endpoints, so its standard deviation is
+/// `sqrt(Σ (w_i sigma_i)^2)`. Correlated noise is not accounted for.
+///
+/// # Returns
+/// `(integral, std_dev)`. Both are NaN if `sigma.len() != y.len()` or the input is
+/// invalid (see [trapezoid_even_f32](crate::trapezoid_even_f32)).
+pub fn trapezoid_even_with_sigma_f32(y: &[f32], dx: f32, sigma: &[f32]) -> (f32, f32) {
+    trapezoid_even_with_sigma(y, dx, sigma)
+}
+
+fn trapezoid_even_with_sigma<T: TrapezSample>(y: &[T], dx: T, sigma: &[T]) -> (T, T)
+where
+    f64: AsPrimitive<T>,
+{
+    let integral = trapezoid_even(y, dx);
+    if sigma.len() != y.len() || integral.is_nan() {
+        return (T::nan(), T::nan());
+    }
+    let n = sigma.len();
+    let interior = pairwise_fold(1, n - 1, &|acc, i| acc + sigma[i] * sigma[i]);
+    let ends = T::HALF * T::HALF * (sigma[0] * sigma[0] + sigma[n - 1] * sigma[n - 1]);
+    (integral, dx * (ends + interior).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_with_error_even() {
+        let sample = |n: usize| {
+            let dx = core::f64::consts::PI / (n - 1) as f64;
+            let y: Vec<f64> = (0..n).map(|i| (i as f64 * dx).sin()).collect();
+            trapezoid_with_error_even_f64(&y, dx)
+        };
+        let (coarse, coarse_err) = sample(17);
+        let (fine, fine_err) = sample(33);
+        // actual errors against the exact value of 2
+        assert!((coarse - 2.).abs() <= 1.1 * coarse_err);
+        assert!((fine - 2.).abs() <= 1.1 * fine_err);
+        let ratio = coarse_err / fine_err;
+        assert!((ratio - 4.).abs() < 0.1, "ratio {ratio}");
+        assert!(trapezoid_with_error_even_f64(&[1., 2.], 1.).1.is_nan());
+    }
+
+    #[test]
+    fn test_method_discrepancy_even() {
+        let sampled = |f: fn(f64) -> f64, n: usize| {
+            let dx = 1. / (n - 1) as f64;
+            let y: Vec<f64> = (0..n).map(|i| f(i as f64 * dx)).collect();
+            method_discrepancy_even_f64(&y, dx)
+        };
+        let smooth = sampled(|x| x.exp(), 201);
+        assert!(smooth < 1e-5, "{smooth}");
+        // about two samples per period
+        let aliased = sampled(|x| (x * 190.).sin(), 61);
+        assert!(aliased > 1e-2, "{aliased}");
+        assert!(method_discrepancy_even_f32(&[1., 2.], 1.).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_even_with_sigma() {
+        let n = 11;
+        let y: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let sigma = vec![0.2; n];
+        let (integral, std_dev) = trapezoid_even_with_sigma_f64(&y, 0.5, &sigma);
+        assert_eq!(integral, 25.);
+        // dx * sigma * sqrt(n - 2 + 2 / 4)
+        let expected = 0.5 * 0.2 * (n as f64 - 1.5).sqrt();
+        assert!((std_dev - expected).abs() < 1e-15, "{std_dev} {expected}");
+        let (integral, std_dev) = trapezoid_even_with_sigma_f32(&[1., 2.], 1., &[0.1]);
+        assert!(integral.is_nan() && std_dev.is_nan());
+    }
+}
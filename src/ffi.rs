@@ -0,0 +1,93 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::{trapezoid_f32, trapezoid_f64};
+
+/// C entry point for [trapezoid_f64] over `n` samples.
+///
+/// Returns NaN if either pointer is null or `n < 2`, otherwise exactly what
+/// [trapezoid_f64] returns for the two slices.
+///
+/// # Safety
+/// Unless null, `y` and `x` must each point to `n` initialized, properly aligned `f64`
+/// values that stay valid and are not written to for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trapezoid_f64_ffi(y: *const f64, x: *const f64, n: usize) -> f64 {
+    if y.is_null() || x.is_null() {
+        return f64::NAN;
+    }
+    // Safety: non-null, and valid for `n` reads per the contract above.
+    let (y, x) = unsafe {
+        (
+            core::slice::from_raw_parts(y, n),
+            core::slice::from_raw_parts(x, n),
+        )
+    };
+    trapezoid_f64(y, x)
+}
+
+/// C entry point for [trapezoid_f32] over `n` samples.
+///
+/// Returns NaN if either pointer is null or `n < 2`, otherwise exactly what
+/// [trapezoid_f32] returns for the two slices.
+///
+/// # Safety
+/// Unless null, `y` and `x` must each point to `n` initialized, properly aligned `f32`
+/// values that stay valid and are not written to for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trapezoid_f32_ffi(y: *const f32, x: *const f32, n: usize) -> f32 {
+    if y.is_null() || x.is_null() {
+        return f32::NAN;
+    }
+    // Safety: non-null, and valid for `n` reads per the contract above.
+    let (y, x) = unsafe {
+        (
+            core::slice::from_raw_parts(y, n),
+            core::slice::from_raw_parts(x, n),
+        )
+    };
+    trapezoid_f32(y, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_ffi() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let result = unsafe { trapezoid_f64_ffi(y.as_ptr(), x.as_ptr(), y.len()) };
+        assert_eq!(result, trapezoid_f64(&y, &x));
+        assert!(unsafe { trapezoid_f64_ffi(core::ptr::null(), x.as_ptr(), 6) }.is_nan());
+        assert!(unsafe { trapezoid_f64_ffi(y.as_ptr(), x.as_ptr(), 1) }.is_nan());
+        let y32 = [1f32, 2., 3.];
+        let result = unsafe { trapezoid_f32_ffi(y32.as_ptr(), y32.as_ptr(), 3) };
+        assert_eq!(result, trapezoid_f32(&y32, &y32));
+    }
+}
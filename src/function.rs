@@ -0,0 +1,370 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num_traits::AsPrimitive;
+
+/// Integrates `f` over `[a, b]` with the trapezoidal rule on `n` equal intervals.
+///
+/// `f` is evaluated at the `n + 1` points `a + i * (b - a) / n`.
+/// If `a > b` the result is the negated integral over `[b, a]`.
+///
+/// Returns NaN if `n == 0`.
+pub fn trapezoid_fn_f64<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> f64 {
+    trapezoid_fn(f, a, b, n)
+}
+
+/// Integrates `f` over `[a, b]` with the trapezoidal rule on `n` equal intervals.
+///
+/// `f` is evaluated at the `n + 1` points `a + i * (b - a) / n`.
+/// If `a > b` the result is the negated integral over `[b, a]`.
+///
+/// Returns NaN if `n == 0`.
+pub fn trapezoid_fn_f32<F: Fn(f32) -> f32>(f: F, a: f32, b: f32, n: usize) -> f32 {
+    trapezoid_fn(f, a, b, n)
+}
+
+pub(crate) fn trapezoid_fn<T: TrapezSample, F: Fn(T) -> T>(f: F, a: T, b: T, n: usize) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    if n == 0 {
+        return T::nan();
+    }
+    let h = (b - a) / (n as f64).as_();
+    // sampling the grid on the fly keeps this allocation free
+    let interior_sum = pairwise_fold(1, n, &|acc, i| acc + f(fmla((i as f64).as_(), h, a)));
+    h * fmla(T::HALF, f(a) + f(b), interior_sum)
+}
+
+#[cfg(feature = "alloc")]
+/// Measures the error of [trapezoid_fn_f64] against a known `reference` value.
+///
+/// For every `n` in `levels` the integral over `[a, b]` is computed on `n` equal
+/// intervals, for a smooth `f` the error should fall by about four each time `n` doubles.
+///
+/// # Returns
+/// `(n, |trapezoid_fn_f64(f, a, b, n) - reference|)` in the order of `levels`,
+/// the error is NaN for `n == 0`.
+pub fn convergence_study_f64<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    reference: f64,
+    levels: &[usize],
+) -> Vec<(usize, f64)> {
+    convergence_study(f, a, b, reference, levels)
+}
+
+#[cfg(feature = "alloc")]
+/// Measures the error of [trapezoid_fn_f32] against a known `reference` value.
+///
+/// For every `n` in `levels` the integral over `[a, b]` is computed on `n` equal
+/// intervals, for a smooth `f` the error should fall by about four each time `n` doubles.
+///
+/// # Returns
+/// `(n, |trapezoid_fn_f32(f, a, b, n) - reference|)` in the order of `levels`,
+/// the error is NaN for `n == 0`.
+pub fn convergence_study_f32<F: Fn(f32) -> f32>(
+    f: F,
+    a: f32,
+    b: f32,
+    reference: f32,
+    levels: &[usize],
+) -> Vec<(usize, f32)> {
+    convergence_study(f, a, b, reference, levels)
+}
+
+#[cfg(feature = "alloc")]
+fn convergence_study<T: TrapezSample, F: Fn(T) -> T>(
+    f: F,
+    a: T,
+    b: T,
+    reference: T,
+    levels: &[usize],
+) -> Vec<(usize, T)>
+where
+    f64: AsPrimitive<T>,
+{
+    levels
+        .iter()
+        .map(|&n| (n, (trapezoid_fn(&f, a, b, n) - reference).abs()))
+        .collect()
+}
+
+/// Integrates `f` over `[a, b]` with the trapezoidal rule refined by repeated interval
+/// doubling and one Richardson extrapolation step.
+///
+/// Level `k` uses `2^k` intervals and evaluates `f` only at the new midpoints, so
+/// `2^levels + 1` evaluations are needed in total. The two finest estimates are
+/// combined as `(4 * T(h / 2) - T(h)) / 3`, which cancels the `h²` error term of the
+/// trapezoidal rule for smooth integrands.
+///
+/// Returns NaN if `levels == 0`.
+pub fn trapezoid_richardson_f64<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, levels: usize) -> f64 {
+    trapezoid_richardson(f, a, b, levels)
+}
+
+/// Integrates `f` over `[a, b]` with the trapezoidal rule refined by repeated interval
+/// doubling and one Richardson extrapolation step.
+///
+/// Level `k` uses `2^k` intervals and evaluates `f` only at the new midpoints, so
+/// `2^levels + 1` evaluations are needed in total. The two finest estimates are
+/// combined as `(4 * T(h / 2) - T(h)) / 3`, which cancels the `h²` error term of the
+/// trapezoidal rule for smooth integrands.
+///
+/// Returns NaN if `levels == 0`.
+pub fn trapezoid_richardson_f32<F: Fn(f32) -> f32>(f: F, a: f32, b: f32, levels: usize) -> f32 {
+    trapezoid_richardson(f, a, b, levels)
+}
+
+fn trapezoid_richardson<T: TrapezSample, F: Fn(T) -> T>(f: F, a: T, b: T, levels: usize) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    if levels == 0 {
+        return T::nan();
+    }
+    let mut h = b - a;
+    let mut coarse = h * T::HALF * (f(a) + f(b));
+    let mut fine = coarse;
+    for level in 0..levels {
+        let midpoints = 1usize << level;
+        let half = h * T::HALF;
+        let new_sum = pairwise_fold(0, midpoints, &|acc, i| {
+            acc + f(fmla(((2 * i + 1) as f64).as_(), half, a))
+        });
+        coarse = fine;
+        fine = fmla(half, new_sum, coarse * T::HALF);
+        h = half;
+    }
+    let three: T = 3f64.as_();
+    fine + (fine - coarse) / three
+}
+
+/// Subdivisions always performed before an interval may be accepted, guards against
+/// the coarse and refined estimates agreeing by coincidence on the first levels.
+const ADAPTIVE_MIN_DEPTH: usize = 3;
+
+/// Adaptive trapezoidal integration of `f` over `[a, b]` to the absolute tolerance `tol`.
+///
+/// Every interval is compared against the sum of its two halves and is split
+/// further, with half of the tolerance going to each half, until the difference
+/// drops below the tolerance or `max_depth` levels were used.
+///
+/// Returns NaN if the depth limit was reached while still above the tolerance,
+/// see [try_adaptive_trapezoid_f64] for the error reporting variant.
+pub fn adaptive_trapezoid_f64<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_depth: usize,
+) -> f64 {
+    try_adaptive_trapezoid(f, a, b, tol, max_depth).unwrap_or(f64::NAN)
+}
+
+/// Adaptive trapezoidal integration of `f` over `[a, b]` to the absolute tolerance `tol`.
+///
+/// Every interval is compared against the sum of its two halves and is split
+/// further, with half of the tolerance going to each half, until the difference
+/// drops below the tolerance or `max_depth` levels were used.
+///
+/// Returns NaN if the depth limit was reached while still above the tolerance,
+/// see [try_adaptive_trapezoid_f32] for the error reporting variant.
+pub fn adaptive_trapezoid_f32<F: Fn(f32) -> f32>(
+    f: F,
+    a: f32,
+    b: f32,
+    tol: f32,
+    max_depth: usize,
+) -> f32 {
+    try_adaptive_trapezoid(f, a, b, tol, max_depth).unwrap_or(f32::NAN)
+}
+
+/// Checked version of [adaptive_trapezoid_f64].
+///
+/// # Errors
+/// [IntegrateError::NotConverged] if some interval still exceeded its tolerance at `max_depth`.
+pub fn try_adaptive_trapezoid_f64<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_depth: usize,
+) -> Result<f64, IntegrateError> {
+    try_adaptive_trapezoid(f, a, b, tol, max_depth)
+}
+
+/// Checked version of [adaptive_trapezoid_f32].
+///
+/// # Errors
+/// [IntegrateError::NotConverged] if some interval still exceeded its tolerance at `max_depth`.
+pub fn try_adaptive_trapezoid_f32<F: Fn(f32) -> f32>(
+    f: F,
+    a: f32,
+    b: f32,
+    tol: f32,
+    max_depth: usize,
+) -> Result<f32, IntegrateError> {
+    try_adaptive_trapezoid(f, a, b, tol, max_depth)
+}
+
+fn try_adaptive_trapezoid<T: TrapezSample, F: Fn(T) -> T>(
+    f: F,
+    a: T,
+    b: T,
+    tol: T,
+    max_depth: usize,
+) -> Result<T, IntegrateError> {
+    let fa = f(a);
+    let fb = f(b);
+    let whole = (b - a) * T::HALF * (fa + fb);
+    let mut converged = true;
+    let value = adaptive_step(&f, a, b, fa, fb, whole, tol, 0, max_depth, &mut converged);
+    if converged {
+        Ok(value)
+    } else {
+        Err(IntegrateError::NotConverged)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn adaptive_step<T: TrapezSample, F: Fn(T) -> T>(
+    f: &F,
+    a: T,
+    b: T,
+    fa: T,
+    fb: T,
+    whole: T,
+    tol: T,
+    depth: usize,
+    max_depth: usize,
+    converged: &mut bool,
+) -> T {
+    let m = (a + b) * T::HALF;
+    let fm = f(m);
+    let quarter = (b - a) * T::HALF * T::HALF;
+    let left = quarter * (fa + fm);
+    let right = quarter * (fm + fb);
+    let halves = left + right;
+    let err = (halves - whole).abs();
+    if depth >= ADAPTIVE_MIN_DEPTH.min(max_depth) && err <= tol {
+        return halves;
+    }
+    if depth >= max_depth {
+        *converged = false;
+        return halves;
+    }
+    let tol = tol * T::HALF;
+    adaptive_step(f, a, m, fa, fm, left, tol, depth + 1, max_depth, converged)
+        + adaptive_step(f, m, b, fm, fb, right, tol, depth + 1, max_depth, converged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_fn_converges() {
+        let mut prev_err = f64::MAX;
+        for n in [4, 16, 64, 256, 1024] {
+            let err = (trapezoid_fn_f64(|x| x * x, 0., 1., n) - 1. / 3.).abs();
+            assert!(err < prev_err);
+            prev_err = err;
+        }
+        assert!(prev_err < 1e-6);
+        assert!(trapezoid_fn_f64(|x| x, 0., 1., 0).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_fn_reversed() {
+        let forward = trapezoid_fn_f32(|x| x * x, 0., 1., 100);
+        let backward = trapezoid_fn_f32(|x| x * x, 1., 0., 100);
+        assert!((forward + backward).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_convergence_study() {
+        let study = convergence_study_f64(|x| x.sin(), 0., 1., 1. - 1f64.cos(), &[8, 16, 32, 64]);
+        assert_eq!(
+            study.iter().map(|s| s.0).collect::<Vec<_>>(),
+            [8, 16, 32, 64]
+        );
+        for pair in study.windows(2) {
+            let ratio = pair[0].1 / pair[1].1;
+            assert!((ratio - 4.).abs() < 0.01, "{ratio}");
+        }
+        assert!(
+            convergence_study_f32(|x| x, 0., 1., 0.5, &[0])[0]
+                .1
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn test_trapezoid_richardson() {
+        let exact = 1f64.exp() - 1.;
+        let extrapolated = trapezoid_richardson_f64(|x| x.exp(), 0., 1., 5);
+        // the finest level of the extrapolation uses the same 33 samples
+        let plain = trapezoid_fn_f64(|x| x.exp(), 0., 1., 32);
+        assert!((extrapolated - exact).abs() < 1e-7);
+        assert!((extrapolated - exact).abs() * 1e3 < (plain - exact).abs());
+        assert!(trapezoid_richardson_f32(|x| x, 0., 1., 0).is_nan());
+    }
+
+    #[test]
+    fn test_adaptive_trapezoid_peak() {
+        use std::cell::Cell;
+        // sharp boundary layer at x = 0
+        let eps = 1e-3;
+        let calls = Cell::new(0usize);
+        let f = |x: f64| {
+            calls.set(calls.get() + 1);
+            (-x / eps).exp()
+        };
+        let exact = eps * (1. - (-1. / eps).exp());
+        let result = adaptive_trapezoid_f64(f, 0., 1., 1e-6, 40);
+        let err = (result - exact).abs();
+        assert!(err < 1e-8, "{result} vs {exact}");
+        // a uniform grid with the same number of evaluations is far less accurate
+        let uniform = trapezoid_fn_f64(f, 0., 1., calls.get() - 1);
+        assert!((uniform - exact).abs() > 100. * err);
+        assert_eq!(
+            try_adaptive_trapezoid_f64(f, 0., 1., 1e-9, 4),
+            Err(IntegrateError::NotConverged)
+        );
+        assert!(adaptive_trapezoid_f32(|x| (-x / 1e-3).exp(), 0., 1., 1e-9, 3).is_nan());
+    }
+}
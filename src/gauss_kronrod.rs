@@ -0,0 +1,210 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use alloc::collections::BinaryHeap;
+use core::cmp::Ordering;
+use num_traits::{AsPrimitive, Float};
+
+/// Kronrod nodes on `[0, 1]`, the odd indices are the 7-point Gauss nodes
+/// and the last entry the shared centre.
+const XGK: [f64; 8] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.20778495500789848,
+    0.,
+];
+/// 15-point Kronrod weights matching [XGK].
+const WGK: [f64; 8] = [
+    0.022935322010529224,
+    0.06309209262997856,
+    0.10479001032225019,
+    0.14065325971552592,
+    0.1690047266392679,
+    0.19035057806478542,
+    0.20443294007529889,
+    0.20948214108472782,
+];
+/// 7-point Gauss weights for `XGK[1]`, `XGK[3]`, `XGK[5]` and the centre.
+const WG: [f64; 4] = [
+    0.1294849661688697,
+    0.27970539148927664,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+/// Upper bound on the number of subintervals, reached only by integrands
+/// the rule cannot resolve to the requested tolerance.
+pub const GAUSS_KRONROD_MAX_SUBDIVISIONS: usize = 1000;
+
+/// Adaptive Gauss–Kronrod quadrature of `f` over `[a, b]` with the 7-15 point pair.
+///
+/// Every interval is estimated with the 15-point Kronrod rule, the difference to the
+/// embedded 7-point Gauss rule is scaled as in QUADPACK into an error estimate. The
+/// interval with the largest error is bisected until the summed error drops below
+/// `tol` or [GAUSS_KRONROD_MAX_SUBDIVISIONS] intervals are in use.
+///
+/// # Returns
+/// `(integral, error)`, an error above `tol` means the subdivision cap was hit.
+pub fn gauss_kronrod_f64<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, tol: f64) -> (f64, f64) {
+    gauss_kronrod(f, a, b, tol)
+}
+
+/// Adaptive Gauss–Kronrod quadrature of `f` over `[a, b]` with the 7-15 point pair.
+///
+/// Every interval is estimated with the 15-point Kronrod rule, the difference to the
+/// embedded 7-point Gauss rule is scaled as in QUADPACK into an error estimate. The
+/// interval with the largest error is bisected until the summed error drops below
+/// `tol` or [GAUSS_KRONROD_MAX_SUBDIVISIONS] intervals are in use.
+///
+/// # Returns
+/// `(integral, error)`, an error above `tol` means the subdivision cap was hit.
+pub fn gauss_kronrod_f32<F: Fn(f32) -> f32>(f: F, a: f32, b: f32, tol: f32) -> (f32, f32) {
+    gauss_kronrod(f, a, b, tol)
+}
+
+/// Subinterval ordered by its error estimate.
+struct Segment<T> {
+    a: T,
+    b: T,
+    value: T,
+    error: T,
+}
+
+impl<T: TrapezSample> PartialEq for Segment<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: TrapezSample> Eq for Segment<T> {}
+
+impl<T: TrapezSample> PartialOrd for Segment<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TrapezSample> Ord for Segment<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // NaN errors sort above everything else
+        match self.error.partial_cmp(&other.error) {
+            Some(order) => order,
+            None => self.error.is_nan().cmp(&other.error.is_nan()),
+        }
+    }
+}
+
+fn gauss_kronrod<T: TrapezSample, F: Fn(T) -> T>(f: F, a: T, b: T, tol: T) -> (T, T)
+where
+    f64: AsPrimitive<T>,
+{
+    let first = kronrod_15(&f, a, b);
+    let mut error = first.error;
+    let mut heap = BinaryHeap::new();
+    heap.push(first);
+    while error > tol && heap.len() < GAUSS_KRONROD_MAX_SUBDIVISIONS {
+        let Some(worst) = heap.pop() else {
+            break;
+        };
+        let mid = (worst.a + worst.b) * T::HALF;
+        let left = kronrod_15(&f, worst.a, mid);
+        let right = kronrod_15(&f, mid, worst.b);
+        error = error - worst.error + left.error + right.error;
+        heap.push(left);
+        heap.push(right);
+    }
+    // summing afresh avoids the drift of the running updates
+    heap.iter()
+        .fold((T::zero(), T::zero()), |(value, error), s| {
+            (value + s.value, error + s.error)
+        })
+}
+
+fn kronrod_15<T: TrapezSample, F: Fn(T) -> T>(f: &F, a: T, b: T) -> Segment<T>
+where
+    f64: AsPrimitive<T>,
+{
+    let mid = (a + b) * T::HALF;
+    let half = (b - a) * T::HALF;
+    let center = f(mid);
+    let mut samples = [(T::zero(), T::zero()); 7];
+    for (sample, &node) in samples.iter_mut().zip(XGK.iter()) {
+        let dx = half * node.as_();
+        *sample = (f(mid - dx), f(mid + dx));
+    }
+    let mut kronrod = center * WGK[7].as_();
+    let mut gauss = center * WG[3].as_();
+    for (i, &(lo, hi)) in samples.iter().enumerate() {
+        kronrod = fmla(WGK[i].as_(), lo + hi, kronrod);
+        if i % 2 == 1 {
+            gauss = fmla(WG[i / 2].as_(), lo + hi, gauss);
+        }
+    }
+    // integral of |f - mean| measures how much of the rule is resolved
+    let mean = kronrod * T::HALF;
+    let mut spread = (center - mean).abs() * WGK[7].as_();
+    for (i, &(lo, hi)) in samples.iter().enumerate() {
+        spread = fmla(WGK[i].as_(), (lo - mean).abs() + (hi - mean).abs(), spread);
+    }
+    let spread = spread * half.abs();
+    let mut error = ((kronrod - gauss) * half).abs();
+    if spread != T::zero() && error != T::zero() {
+        let scale: T = 200f64.as_();
+        let ratio = Float::powf(scale * error / spread, 1.5f64.as_());
+        error = spread * ratio.min(T::one());
+    }
+    Segment {
+        a,
+        b,
+        value: kronrod * half,
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauss_kronrod_oscillatory() {
+        let exact = 60f64.sin() / 30.;
+        let tol = 1e-10;
+        let (value, error) = gauss_kronrod_f64(|x| (30. * x).cos(), 0., 2., tol);
+        assert!(error < tol, "{error}");
+        assert!((value - exact).abs() < tol, "{value} vs {exact}");
+
+        let (value, error) = gauss_kronrod_f32(|x| x * x, 0., 3., 1e-5);
+        assert!((value - 9.).abs() < 1e-5 && error < 1e-5);
+    }
+}
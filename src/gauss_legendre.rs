@@ -0,0 +1,202 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use num_traits::{AsPrimitive, Float};
+
+/// Non-negative Gauss–Legendre nodes with their weights on `[-1, 1]` for orders 2 to 10,
+/// the negative nodes mirror them with the same weights.
+const TABLES: [&[(f64, f64)]; 9] = [
+    &[(0.5773502691896257, 1.0)],
+    &[
+        (0., 0.8888888888888888),
+        (0.7745966692414834, 0.5555555555555556),
+    ],
+    &[
+        (0.33998104358485626, 0.6521451548625461),
+        (0.8611363115940526, 0.34785484513745385),
+    ],
+    &[
+        (0., 0.5688888888888889),
+        (0.5384693101056831, 0.47862867049936647),
+        (0.906179845938664, 0.23692688505618908),
+    ],
+    &[
+        (0.2386191860831969, 0.46791393457269104),
+        (0.6612093864662645, 0.3607615730481386),
+        (0.932469514203152, 0.17132449237917036),
+    ],
+    &[
+        (0., 0.4179591836734694),
+        (0.4058451513773972, 0.3818300505051189),
+        (0.7415311855993945, 0.27970539148927664),
+        (0.9491079123427585, 0.1294849661688697),
+    ],
+    &[
+        (0.1834346424956498, 0.362683783378362),
+        (0.525532409916329, 0.31370664587788727),
+        (0.7966664774136267, 0.22238103445337448),
+        (0.9602898564975363, 0.10122853629037626),
+    ],
+    &[
+        (0., 0.3302393550012598),
+        (0.3242534234038089, 0.31234707704000286),
+        (0.6133714327005904, 0.26061069640293544),
+        (0.8360311073266358, 0.1806481606948574),
+        (0.9681602395076261, 0.08127438836157441),
+    ],
+    &[
+        (0.14887433898163122, 0.29552422471475287),
+        (0.4333953941292472, 0.26926671930999635),
+        (0.6794095682990244, 0.21908636251598204),
+        (0.8650633666889845, 0.1494513491505806),
+        (0.9739065285171717, 0.06667134430868814),
+    ],
+];
+
+/// Integrates `f` over `[a, b]` with `order`-point Gauss–Legendre quadrature.
+///
+/// Exact for polynomials up to degree `2 * order - 1`. Nodes and weights for orders
+/// 2 to 10 are tabulated, any other order computes them by Newton iteration on the
+/// Legendre recurrence, which costs `O(order²)` but allocates nothing.
+/// `f` is never evaluated at the endpoints.
+///
+/// Returns NaN if `order == 0`.
+pub fn gauss_legendre_f64<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, order: usize) -> f64 {
+    gauss_legendre(f, a, b, order)
+}
+
+/// Integrates `f` over `[a, b]` with `order`-point Gauss–Legendre quadrature.
+///
+/// Exact for polynomials up to degree `2 * order - 1`. Nodes and weights for orders
+/// 2 to 10 are tabulated, any other order computes them by Newton iteration on the
+/// Legendre recurrence, which costs `O(order²)` but allocates nothing.
+/// `f` is never evaluated at the endpoints.
+///
+/// Returns NaN if `order == 0`.
+pub fn gauss_legendre_f32<F: Fn(f32) -> f32>(f: F, a: f32, b: f32, order: usize) -> f32 {
+    gauss_legendre(f, a, b, order)
+}
+
+fn gauss_legendre<T: TrapezSample, F: Fn(T) -> T>(f: F, a: T, b: T, order: usize) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    if order == 0 {
+        return T::nan();
+    }
+    let mid = (a + b) * T::HALF;
+    let half = (b - a) * T::HALF;
+    let pair = |acc: T, (node, weight): (f64, f64)| {
+        let x: T = node.as_();
+        let sample = if node == 0. {
+            f(mid)
+        } else {
+            f(fmla(-half, x, mid)) + f(fmla(half, x, mid))
+        };
+        fmla(weight.as_(), sample, acc)
+    };
+    let sum = match TABLES.get(order.wrapping_sub(2)) {
+        Some(table) => table.iter().copied().fold(T::zero(), pair),
+        None => (0..order.div_ceil(2))
+            .map(|i| legendre_node(order, i))
+            .fold(T::zero(), pair),
+    };
+    half * sum
+}
+
+/// `i`-th non-negative root of the Legendre polynomial `P_n` and its quadrature weight,
+/// counting from the largest root.
+fn legendre_node(n: usize, i: usize) -> (f64, f64) {
+    let nf = n as f64;
+    let mut x = Float::cos(core::f64::consts::PI * (i as f64 + 0.75) / (nf + 0.5));
+    let mut dp = 1.;
+    for _ in 0..100 {
+        let (mut p0, mut p1) = (1., x);
+        for k in 2..=n {
+            let k = k as f64;
+            let p2 = ((2. * k - 1.) * x * p1 - (k - 1.) * p0) / k;
+            p0 = p1;
+            p1 = p2;
+        }
+        dp = nf * (x * p1 - p0) / (x * x - 1.);
+        let step = p1 / dp;
+        x -= step;
+        if step.abs() <= 1e-16 {
+            break;
+        }
+    }
+    if 2 * i + 1 == n {
+        // the middle root of an odd order
+        x = 0.;
+        dp = legendre_derivative_at_zero(n);
+    }
+    (x, 2. / ((1. - x * x) * dp * dp))
+}
+
+/// `P_n'(0)` for odd `n`, where Newton iteration is not needed.
+fn legendre_derivative_at_zero(n: usize) -> f64 {
+    // P_n'(0) = n * P_{n-1}(0), P_{2m}(0) = (-1)^m (2m - 1)!! / (2m)!!
+    let mut p = 1f64;
+    for k in (2..n).step_by(2) {
+        p *= -((k - 1) as f64) / k as f64;
+    }
+    n as f64 * p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauss_legendre_polynomial() {
+        let f = |x: f64| 3. * x.powi(9) - x.powi(6) + 2. * x * x - 1.;
+        let anti = |x: f64| 0.3 * x.powi(10) - x.powi(7) / 7. + 2. * x * x * x / 3. - x;
+        let exact = anti(2.) - anti(-0.5);
+        for order in [5, 7, 12, 21] {
+            let result = gauss_legendre_f64(f, -0.5, 2., order);
+            assert!(
+                (result - exact).abs() < 1e-12 * exact.abs(),
+                "{order}: {result}"
+            );
+        }
+        assert!(gauss_legendre_f32(|x| x, 0., 1., 0).is_nan());
+    }
+
+    #[test]
+    fn test_gauss_legendre_nodes() {
+        for (order, table) in (2..).zip(TABLES) {
+            for (i, &(node, weight)) in table.iter().rev().enumerate() {
+                let (x, w) = legendre_node(order, i);
+                assert!((x - node).abs() < 1e-15, "{order} {i}: {x} vs {node}");
+                assert!((w - weight).abs() < 1e-14, "{order} {i}: {w} vs {weight}");
+            }
+        }
+    }
+}
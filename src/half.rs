@@ -0,0 +1,125 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Half precision samples.
+//!
+//! [trapezoid_f16] and [trapezoid_bf16] widen every sample to `f32` and accumulate there.
+//!
+//! Both types also implement `TrapezSample`. The trait has no room for a separate
+//! accumulator type, so the generic routines such as [trapezoid](crate::trapezoid)
+//! accumulate in the half type itself and round after every operation. Prefer the
+//! wrappers here for long inputs.
+use crate::TrapezSample;
+use crate::promote::try_trapezoid_promoted;
+use half::{bf16, f16};
+
+impl TrapezSample for f16 {
+    const TOLERANCE: Self = f16::from_f32_const(1e-2);
+    const HALF: Self = f16::from_f32_const(0.5);
+}
+
+impl TrapezSample for bf16 {
+    const TOLERANCE: Self = bf16::from_f32_const(5e-2);
+    const HALF: Self = bf16::from_f32_const(0.5);
+}
+
+/// Calculates the definite integral of half precision samples using the trapezoidal rule.
+///
+/// Samples are widened to `f32` as they are read and the whole computation
+/// runs in `f32`, only the result is rounded back to `f16`.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of monotonic abscissas.
+///
+/// # Returns
+/// The approximate definite integral, or NaN if the input is invalid
+/// (see [try_trapezoid_f32](crate::try_trapezoid_f32)).
+pub fn trapezoid_f16(y: &[f16], x: &[f16]) -> f16 {
+    let integral = try_trapezoid_promoted(y, x, f16::to_f32, f16::to_f32).unwrap_or(f32::NAN);
+    f16::from_f32(integral)
+}
+
+/// Calculates the definite integral of bfloat16 samples using the trapezoidal rule.
+///
+/// Samples are widened to `f32` as they are read and the whole computation
+/// runs in `f32`, only the result is rounded back to `bf16`.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of monotonic abscissas.
+///
+/// # Returns
+/// The approximate definite integral, or NaN if the input is invalid
+/// (see [try_trapezoid_f32](crate::try_trapezoid_f32)).
+pub fn trapezoid_bf16(y: &[bf16], x: &[bf16]) -> bf16 {
+    let integral = try_trapezoid_promoted(y, x, bf16::to_f32, bf16::to_f32).unwrap_or(f32::NAN);
+    bf16::from_f32(integral)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_f16_accumulation() {
+        let y = vec![f16::from_f32(0.01); 1000];
+        let x: Vec<f16> = (0..1000).map(|i| f16::from_f32(i as f32 * 0.5)).collect();
+        let reference: f64 = x
+            .windows(2)
+            .zip(y.windows(2))
+            .map(|(x, y)| 0.5 * (x[1].to_f64() - x[0].to_f64()) * (y[0].to_f64() + y[1].to_f64()))
+            .sum();
+        // naive accumulation in f16
+        let mut naive = f16::ZERO;
+        for (x, y) in x.windows(2).zip(y.windows(2)) {
+            naive += f16::from_f32(0.5) * (x[1] - x[0]) * (y[0] + y[1]);
+        }
+        let result = trapezoid_f16(&y, &x);
+        let err = (result.to_f64() - reference).abs();
+        assert!(err * 10. < (naive.to_f64() - reference).abs());
+        assert!(err < reference * 1e-3);
+        assert!(trapezoid_bf16(&[bf16::ONE], &[bf16::ONE]).is_nan());
+        assert_eq!(
+            trapezoid_bf16(&[bf16::ONE, bf16::ONE], &[bf16::ZERO, bf16::from_f32(2.)]),
+            bf16::from_f32(2.)
+        );
+    }
+
+    #[test]
+    fn test_generic_half() {
+        let x = [0., 0.5, 1.5, 2.].map(f16::from_f32);
+        let y = [1., 2., 3., 1.].map(f16::from_f32);
+        assert_eq!(crate::trapezoid(&y, &x), trapezoid_f16(&y, &x));
+        let y = [1., 3., 2.].map(bf16::from_f32);
+        assert_eq!(
+            crate::trapezoid_even(&y, bf16::from_f32(0.25)),
+            bf16::from_f32(1.125)
+        );
+    }
+}
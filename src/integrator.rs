@@ -0,0 +1,225 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::error::IntegrateError;
+use crate::nan::{NanPolicy, nan_segment_sum, segment_start};
+use crate::quadrature::{Method, integrate_f64};
+use crate::sum::neumaier_fold;
+use crate::trapezoid::{Grid, classify_iter, detect_overflow, integrate_signed, validate};
+
+/// How the trapezoid rule accumulates its terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Accum {
+    /// The summation the crate is built with: pairwise, or compensated under `high_accuracy`.
+    #[default]
+    Pairwise,
+    /// Neumaier's improved Kahan summation, independent of the build features.
+    Kahan,
+}
+
+/// Configurable integration of sampled data.
+///
+/// The defaults match [try_trapezoid_f64](crate::try_trapezoid_f64), every setter
+/// changes one aspect and returns the updated integrator.
+///
+/// ```
+/// use trapez_integrate::{Accum, Integrator, Method, NanPolicy};
+///
+/// let integrator = Integrator::new()
+///     .nan_policy(NanPolicy::Skip)
+///     .accumulation(Accum::Kahan);
+/// let y = [5., 6., f64::NAN, 4., 6., 2.];
+/// let x = [1., 2., 4., 6., 7., 9.];
+/// assert_eq!(integrator.integrate(&y, &x), Ok(18.5));
+/// assert_eq!(Integrator::new().method(Method::Simpson).integrate(&[0., 1., 4.], &[0., 1., 2.]), Ok(8. / 3.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Integrator {
+    method: Method,
+    rel_tol: f64,
+    nan_policy: Option<NanPolicy>,
+    accumulation: Accum,
+}
+
+impl Default for Integrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Integrator {
+    /// The trapezoid rule with the default tolerance, NaN samples propagate.
+    pub const fn new() -> Self {
+        Self {
+            method: Method::Trapezoid,
+            rel_tol: 1e-12,
+            nan_policy: None,
+            accumulation: Accum::Pairwise,
+        }
+    }
+
+    /// Selects the integration rule, see [Method].
+    pub const fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Relative tolerance of the uniform spacing detection of the trapezoid rule,
+    /// see [trapezoid_with_tol_f64](crate::trapezoid_with_tol_f64).
+    pub const fn rel_tol(mut self, rel_tol: f64) -> Self {
+        self.rel_tol = rel_tol;
+        self
+    }
+
+    /// Treatment of NaN samples by the trapezoid rule,
+    /// see [trapezoid_nan_ignore_f64](crate::trapezoid_nan_ignore_f64).
+    pub const fn nan_policy(mut self, policy: NanPolicy) -> Self {
+        self.nan_policy = Some(policy);
+        self
+    }
+
+    /// Summation of the trapezoid rule terms, see [Accum].
+    pub const fn accumulation(mut self, accumulation: Accum) -> Self {
+        self.accumulation = accumulation;
+        self
+    }
+
+    /// Integrates `y` over `x` with the configured settings.
+    ///
+    /// The tolerance, NaN policy and accumulation apply to [Method::Trapezoid],
+    /// the other methods follow their own rules as in [integrate_f64].
+    ///
+    /// # Errors
+    /// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples, or fewer
+    ///   than two that are not NaN when a NaN policy is set.
+    /// * [IntegrateError::LengthMismatch] if `y` and `x` lengths differ.
+    /// * [IntegrateError::NonMonotonic] if `x` changes direction.
+    /// * [IntegrateError::Overflow] if all samples are finite but the integral is not.
+    /// * [IntegrateError::Divergent] if the trapezoid rule meets an infinite sample,
+    ///   NaN samples are missing data and follow the [NanPolicy] instead.
+    /// * [IntegrateError::UnsupportedGrid] if another method cannot handle finite input.
+    pub fn integrate(&self, y: &[f64], x: &[f64]) -> Result<f64, IntegrateError> {
+        validate(y, x)?;
+        let grid = classify_iter(x.iter().copied(), self.rel_tol)?;
+        if self.method != Method::Trapezoid {
+            let value = integrate_f64(self.method, y, x);
+            if value.is_nan() && y.iter().chain(x).all(|v| v.is_finite()) {
+                return Err(IntegrateError::UnsupportedGrid);
+            }
+            return Ok(value);
+        }
+        let integral = self.trapezoid(y, x, grid)?;
+        let integral = if grid.descending { -integral } else { integral };
+        detect_overflow(integral, y, x)
+    }
+
+    /// Signed trapezoid rule honouring the NaN policy and accumulation.
+    fn trapezoid(&self, y: &[f64], x: &[f64], grid: Grid<f64>) -> Result<f64, IntegrateError> {
+        let n = y.len();
+        let area = |i: usize, j: usize| 0.5 * (x[j] - x[i]) * (y[i] + y[j]);
+        let policy = self.nan_policy.filter(|_| y.iter().any(|v| v.is_nan()));
+        let Some(policy) = policy else {
+            return Ok(match (self.accumulation, grid.step) {
+                (Accum::Pairwise, step) => integrate_signed(y, x, step),
+                (Accum::Kahan, Some(h)) => {
+                    h * (0.5 * (y[0] + y[n - 1]) + neumaier_fold(y[1..n - 1].iter().copied()))
+                }
+                (Accum::Kahan, None) => neumaier_fold((0..n - 1).map(|i| area(i, i + 1))),
+            });
+        };
+        if y.iter().filter(|v| !v.is_nan()).nth(1).is_none() {
+            return Err(IntegrateError::TooFewPoints);
+        }
+        Ok(match self.accumulation {
+            Accum::Pairwise => nan_segment_sum(y, x, policy),
+            Accum::Kahan => neumaier_fold(
+                (1..n).filter_map(|i| segment_start(y, policy, i).map(|j| area(j, i))),
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{trapezoid_even_f64, trapezoid_f64, trapezoid_nan_ignore_f64};
+
+    #[test]
+    fn test_integrator_settings() {
+        let x: Vec<f64> = (0..9)
+            .map(|i| i as f64 * 0.1 + (i % 2) as f64 * 1e-10)
+            .collect();
+        let y: Vec<f64> = x.iter().map(|&v| v * v).collect();
+        let default = Integrator::new();
+        assert_eq!(default.integrate(&y, &x), Ok(trapezoid_f64(&y, &x)));
+        let loose = default.rel_tol(1e-6).integrate(&y, &x);
+        assert_eq!(loose, Ok(trapezoid_even_f64(&y, x[1] - x[0])));
+
+        let simpson = default.method(Method::Simpson).integrate(&y, &x).unwrap();
+        assert!((simpson - x[8].powi(3) / 3.).abs() < 1e-12);
+        let boole = default.method(Method::Boole).integrate(&y, &x);
+        assert_eq!(boole, Err(IntegrateError::UnsupportedGrid));
+
+        let gappy = [5., 6., f64::NAN, 4., 6., 2.];
+        let grid = [1., 2., 4., 6., 7., 9.];
+        assert!(default.integrate(&gappy, &grid).unwrap().is_nan());
+        let skip = default.nan_policy(NanPolicy::Skip);
+        assert_eq!(skip.integrate(&gappy, &grid), Ok(18.5));
+        let bridge = default.nan_policy(NanPolicy::Interpolate);
+        assert_eq!(bridge.integrate(&gappy, &grid), Ok(38.5));
+        let y: Vec<f64> = (0..1000)
+            .map(|i| {
+                if i % 7 == 3 {
+                    f64::NAN
+                } else {
+                    (i as f64 * 0.01).sin()
+                }
+            })
+            .collect();
+        let x: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.01).powi(2)).collect();
+        for policy in [NanPolicy::Skip, NanPolicy::Interpolate] {
+            let expected = trapezoid_nan_ignore_f64(&y, &x, policy);
+            assert_eq!(default.nan_policy(policy).integrate(&y, &x), Ok(expected));
+        }
+
+        // the interior sum cancels completely unless it is compensated
+        let y = [0., 1., 1e100, 1., -1e100, 0.];
+        let x = [0., 1., 2., 3., 4., 5.];
+        let kahan = default.accumulation(Accum::Kahan);
+        assert_eq!(kahan.integrate(&y, &x), Ok(2.));
+        if !cfg!(feature = "high_accuracy") {
+            assert_eq!(default.integrate(&y, &x), Ok(0.));
+        }
+        assert_eq!(
+            kahan
+                .nan_policy(NanPolicy::Skip)
+                .integrate(&[f64::NAN, 1.], &[0., 1.]),
+            Err(IntegrateError::TooFewPoints)
+        );
+    }
+}
@@ -26,148 +26,232 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use crate::mla::fmla;
-use num_traits::{AsPrimitive, Float, MulAdd};
-use std::ops::AddAssign;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+use core::ops::AddAssign;
+use num_traits::Float;
+
+mod abs;
+mod arc_length;
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod avx;
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod avx512;
+#[cfg(feature = "ndarray")]
+mod axis;
+mod batch;
+#[cfg(feature = "bigdecimal")]
+mod big_decimal;
+mod clenshaw_curtis;
+#[cfg(feature = "num-complex")]
+mod complex;
+mod cumulative;
+#[cfg(feature = "alloc")]
+mod deque;
+mod detailed;
+mod double;
+#[cfg(feature = "double_double")]
+mod double_double;
+mod energy;
+mod error;
+mod estimate;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "fixed")]
+mod fixed;
+mod function;
+#[cfg(feature = "alloc")]
+mod gauss_kronrod;
+mod gauss_legendre;
+#[cfg(feature = "half")]
+mod half;
+mod integrator;
+mod log_x;
+mod mean;
+mod midpoint;
 mod mla;
+mod nan;
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    target_arch = "aarch64"
+))]
+mod neon;
+mod newton_cotes;
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "alloc")]
+mod piecewise;
+pub mod prelude;
+mod promote;
+mod quadrature;
+mod range;
+mod riemann;
+mod romberg;
+#[cfg(feature = "polars")]
+mod series;
+mod simpson;
+mod sort;
+#[cfg(feature = "alloc")]
+mod spline;
+mod streaming;
+mod strided;
+mod sum;
+mod symmetric;
+mod tanh_sinh;
+#[cfg(feature = "chrono")]
+mod timeseries;
+mod trapezoid;
+mod weighted;
+
+pub use abs::{trapezoid_abs_f32, trapezoid_abs_f64};
+pub use arc_length::{
+    arc_length_f32, arc_length_f64, arc_length_smooth_f32, arc_length_smooth_f64,
+};
+#[cfg(feature = "ndarray")]
+pub use axis::trapezoid_axis;
+#[cfg(feature = "alloc")]
+pub use batch::{trapezoid_batch_f32, trapezoid_batch_f64};
+pub use batch::{trapezoid_batch_into_f32, trapezoid_batch_into_f64};
+#[cfg(feature = "bigdecimal")]
+pub use big_decimal::try_trapezoid_big;
+pub use clenshaw_curtis::{clenshaw_curtis_f32, clenshaw_curtis_f64};
+#[cfg(feature = "num-complex")]
+pub use complex::{trapezoid_complex_f32, trapezoid_complex_f64};
+#[cfg(feature = "alloc")]
+pub use cumulative::{
+    cumtrapz_f32, cumtrapz_f64, cumulative_simpson_even_f32, cumulative_simpson_even_f64,
+};
+pub use cumulative::{
+    cumtrapz_into_f32, cumtrapz_into_f64, trapezoid_until_f32, trapezoid_until_f64,
+};
+#[cfg(feature = "alloc")]
+pub use deque::{trapezoid_even_deque_f32, trapezoid_even_deque_f64};
+pub use detailed::{
+    TrapezoidResult, trapezoid_detailed_f32, trapezoid_detailed_f64,
+    trapezoid_with_max_segment_f32, trapezoid_with_max_segment_f64,
+};
+pub use double::{
+    trapezoid_2d_f32, trapezoid_2d_f64, trapezoid_2d_nonuniform_f32, trapezoid_2d_nonuniform_f64,
+};
+#[cfg(feature = "double_double")]
+pub use double_double::trapezoid_even_dd_f64;
+pub use energy::{
+    trapezoid_energy_exact_f32, trapezoid_energy_exact_f64, trapezoid_energy_f32,
+    trapezoid_energy_f64,
+};
+pub use error::IntegrateError;
+pub use estimate::{
+    method_discrepancy_even_f32, method_discrepancy_even_f64, trapezoid_even_with_sigma_f32,
+    trapezoid_even_with_sigma_f64, trapezoid_with_error_even_f32, trapezoid_with_error_even_f64,
+};
+#[cfg(feature = "ffi")]
+pub use ffi::{trapezoid_f32_ffi, trapezoid_f64_ffi};
+#[cfg(feature = "fixed")]
+pub use fixed::try_trapezoid_i16f16;
+pub use function::{
+    adaptive_trapezoid_f32, adaptive_trapezoid_f64, trapezoid_fn_f32, trapezoid_fn_f64,
+    trapezoid_richardson_f32, trapezoid_richardson_f64, try_adaptive_trapezoid_f32,
+    try_adaptive_trapezoid_f64,
+};
+#[cfg(feature = "alloc")]
+pub use function::{convergence_study_f32, convergence_study_f64};
+#[cfg(feature = "alloc")]
+pub use gauss_kronrod::{GAUSS_KRONROD_MAX_SUBDIVISIONS, gauss_kronrod_f32, gauss_kronrod_f64};
+pub use gauss_legendre::{gauss_legendre_f32, gauss_legendre_f64};
+#[cfg(feature = "half")]
+pub use half::{trapezoid_bf16, trapezoid_f16};
+pub use integrator::{Accum, Integrator};
+pub use log_x::{trapezoid_log_x_f32, trapezoid_log_x_f64};
+pub use mean::{
+    normalize_density_f32, normalize_density_f64, trapezoid_centroid_f32, trapezoid_centroid_f64,
+    trapezoid_even_mean_f32, trapezoid_even_mean_f64, trapezoid_mean_f32, trapezoid_mean_f64,
+};
+pub use midpoint::{midpoint_even_f32, midpoint_even_f64, midpoint_f32, midpoint_f64};
+pub use mla::FUSED_MULTIPLY_ADD;
+pub use nan::{
+    NanPolicy, trapezoid_finite_prefix_f32, trapezoid_finite_prefix_f64, trapezoid_nan_ignore_f32,
+    trapezoid_nan_ignore_f64,
+};
+pub use newton_cotes::{boole_even_f32, boole_even_f64, weddle_even_f32, weddle_even_f64};
+#[cfg(feature = "parallel")]
+pub use parallel::{par_trapezoid_even_f32, par_trapezoid_even_f64};
+#[cfg(feature = "alloc")]
+pub use piecewise::{trapezoid_segments_f32, trapezoid_segments_f64};
+pub use quadrature::{
+    Boole, Method, Quadrature, Simpson, Trapezoid, integrate_best_even_f64, integrate_f64,
+};
+pub use range::{
+    trapezoid_extrapolated_f32, trapezoid_extrapolated_f64, trapezoid_range_f32,
+    trapezoid_range_f64,
+};
+pub use riemann::{riemann_left_f32, riemann_left_f64, riemann_right_f32, riemann_right_f64};
+pub use romberg::{romberg_even_f32, romberg_even_f64};
+#[cfg(feature = "polars")]
+pub use series::{NullPolicy, trapezoid_series};
+pub use simpson::{
+    simpson_even_f32, simpson_even_f64, simpson_f32, simpson_f64, simpson38_even_f32,
+    simpson38_even_f64,
+};
+pub use sort::{sort_and_trapezoid_f32, sort_and_trapezoid_f64};
+#[cfg(feature = "alloc")]
+pub use spline::{spline_integrate_f32, spline_integrate_f64};
+pub use streaming::{StreamingTrapezoid, trapezoid_iter_f32, trapezoid_iter_f64};
+pub use strided::{trapezoid_even_strided_f32, trapezoid_even_strided_f64};
+pub use symmetric::{trapezoid_symmetric_parts_f32, trapezoid_symmetric_parts_f64};
+pub use tanh_sinh::{tanh_sinh_f32, tanh_sinh_f64, try_tanh_sinh_f32, try_tanh_sinh_f64};
+#[cfg(feature = "chrono")]
+pub use timeseries::trapezoid_timeseries_f64;
+pub use trapezoid::{
+    DuplicateXPolicy, integration_domain_f32, integration_domain_f64, is_uniformly_spaced_f32,
+    is_uniformly_spaced_f64, trapezoid, trapezoid_any, trapezoid_even, trapezoid_even_array,
+    trapezoid_even_const, trapezoid_even_f32, trapezoid_even_f64, trapezoid_even_unchecked_f32,
+    trapezoid_even_unchecked_f64, trapezoid_f32, trapezoid_f32_acc64, trapezoid_f64,
+    trapezoid_int_f64, trapezoid_mixed_f64, trapezoid_or_zero_f32, trapezoid_or_zero_f64,
+    trapezoid_periodic_even_f32, trapezoid_periodic_even_f64, trapezoid_segment_areas_f32,
+    trapezoid_segment_areas_f64, trapezoid_signed_f32, trapezoid_signed_f64,
+    trapezoid_single_pass_f32, trapezoid_single_pass_f64, trapezoid_with_dx_f32,
+    trapezoid_with_dx_f64, trapezoid_with_tol_f32, trapezoid_with_tol_f64, try_trapezoid_even_f32,
+    try_trapezoid_even_f64, try_trapezoid_even_finite_f32, try_trapezoid_even_finite_f64,
+    try_trapezoid_f32, try_trapezoid_f64, try_trapezoid_wide_f32,
+    try_trapezoid_with_duplicates_f32, try_trapezoid_with_duplicates_f64,
+};
+pub use weighted::{
+    trapezoid_weighted_f32, trapezoid_weighted_f64, trapezoid_weighted_fn_f32,
+    trapezoid_weighted_fn_f64, trapezoid_weights_into_f32, trapezoid_weights_into_f64,
+};
+#[cfg(feature = "alloc")]
+pub use weighted::{trapezoid_weights_f32, trapezoid_weights_f64};
 
-pub(crate) trait TrapezSample:
-    Float + PartialOrd + PartialEq + AddAssign + MulAdd<Self, Output = Self> + 'static
-{
+/// Floating point sample type accepted by the generic integration routines.
+///
+/// Implemented for `f32` and `f64`.
+pub trait TrapezSample: Float + PartialOrd + PartialEq + AddAssign + 'static {
+    /// Relative tolerance used to decide whether abscissas are uniformly spaced.
     const TOLERANCE: Self;
+    /// The constant `0.5`.
+    const HALF: Self;
 }
 
 impl TrapezSample for f32 {
     const TOLERANCE: Self = 1e-6;
+    const HALF: Self = 0.5;
 }
 
 impl TrapezSample for f64 {
     const TOLERANCE: Self = 1e-12;
-}
-
-/// Calculates the definite integral of a dataset using the trapezoidal rule.
-///
-/// This implementation handles non-uniform spacing between x-values by
-/// calculating the area of each trapezoidal segment directly.
-///
-/// # Arguments
-/// * `y` - The array of function values.
-/// * `x` - The array of abscissas.
-///
-/// # Returns
-/// The approximate definite integral (area under the curve).
-pub fn trapezoid_f64(y: &[f64], x: &[f64]) -> f64 {
-    trapezoid(y, x)
-}
-
-/// Calculates the definite integral of a dataset using the trapezoidal rule.
-///
-/// This implementation handles non-uniform spacing between x-values by
-/// calculating the area of each trapezoidal segment directly.
-///
-/// # Arguments
-/// * `y` - The array of function values.
-/// * `x` - The array of abscissas.
-///
-/// # Returns
-/// The approximate definite integral (area under the curve).
-pub fn trapezoid_f32(y: &[f32], x: &[f32]) -> f32 {
-    trapezoid(y, x)
-}
-
-fn trapezoid<T: TrapezSample>(y: &[T], x: &[T]) -> T
-where
-    f64: AsPrimitive<T>,
-{
-    let n = y.len();
-    if n < 2 || x.len() != n {
-        return T::nan();
-    }
-
-    // Quick check for exact uniform spacing using first interval.
-    let h0 = x[1] - x[0];
-    // tolerance scaled to magnitude of h0 (and at least a tiny absolute tol)
-    let tol = (h0.abs().max(1.0.as_())) * T::TOLERANCE;
-
-    let mut uniform = true;
-    let q = &x[1..];
-    for x in q.windows(2) {
-        let hi = x[1] - x[0];
-        if (hi - h0).abs() > tol {
-            uniform = false;
-            break;
-        }
-    }
-
-    if uniform {
-        // Use the optimized uniform-spacing trapezoid rule:
-        // integral = h * ( 0.5*y0 + sum(y[1..n-1]) + 0.5*yn )
-        let mut interior_sum = T::zero();
-        for v in &y[1..(n - 1)] {
-            interior_sum += *v;
-        }
-        h0 * fmla(y[0] + y[n - 1], 0.5f64.as_(), interior_sum)
-    } else {
-        // General (non-uniform) trapezoid rule
-        let mut integral = T::zero();
-        for (y, x) in y.windows(2).zip(x.windows(2)) {
-            let dx = x[1] - x[0];
-            integral = fmla(dx * 0.5f64.as_(), y[0] + y[1], integral);
-        }
-        integral
-    }
-}
-
-/// Trapezoidal integration for evenly spaced samples.
-/// `y` = function values
-/// `dx` = spacing between x-values
-pub fn trapezoid_even_f32(y: &[f32], dx: f32) -> f32 {
-    trapezoid_even(y, dx)
-}
-
-/// Trapezoidal integration for evenly spaced samples.
-/// `y` = function values
-/// `dx` = spacing between x-values
-pub fn trapezoid_even_f64(y: &[f64], dx: f64) -> f64 {
-    trapezoid_even(y, dx)
-}
-
-/// Trapezoidal integration for evenly spaced samples.
-/// `y` = function values
-/// `dx` = spacing between x-values
-fn trapezoid_even<T: TrapezSample>(y: &[T], dx: T) -> T
-where
-    f64: AsPrimitive<T>,
-{
-    let n = y.len();
-    if n < 2 || dx <= 0.0f64.as_() {
-        return T::nan();
-    }
-
-    // sum of interior terms
-    let mut sum = T::zero();
-    let q = &y[1..n - 1];
-    for &v in q.iter() {
-        sum += v;
-    }
-
-    dx * fmla(0.5f64.as_(), y[0] + y[n - 1], sum)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_trapezoid() {
-        let result = trapezoid_f64(&[5., 6., 1., 4., 6., 2.], &[1., 2., 4., 6., 7., 9.]);
-        assert_eq!(result, 30.5);
-    }
-
-    #[test]
-    fn test_trapezoid_even() {
-        let result = trapezoid_even(&[5., 6., 1., 4., 6., 2.], 0.003);
-        assert_eq!(result, 0.0615);
-    }
+    const HALF: Self = 0.5;
 }
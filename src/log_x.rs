@@ -0,0 +1,95 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{classify, validate};
+
+/// Integrates `y` over logarithmically spaced abscissas `x`.
+///
+/// Substitutes `u = ln(x)` and applies the trapezoidal rule to `y * x` in `u`, every
+/// segment contributes `0.5 * (y[i] * x[i] + y[i + 1] * x[i + 1]) * ln(x[i + 1] / x[i])`.
+/// On a geometric grid this follows power laws far better than plain trapezoid in `x`,
+/// `1 / x` is integrated exactly. `x` follows the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The approximate definite integral, or NaN if the lengths differ, fewer than two
+/// samples are given, `x` is not monotonic or any abscissa is not positive.
+pub fn trapezoid_log_x_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_log_x(y, x)
+}
+
+/// Integrates `y` over logarithmically spaced abscissas `x`.
+///
+/// Substitutes `u = ln(x)` and applies the trapezoidal rule to `y * x` in `u`, every
+/// segment contributes `0.5 * (y[i] * x[i] + y[i + 1] * x[i + 1]) * ln(x[i + 1] / x[i])`.
+/// On a geometric grid this follows power laws far better than plain trapezoid in `x`,
+/// `1 / x` is integrated exactly. `x` follows the rules of [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The approximate definite integral, or NaN if the lengths differ, fewer than two
+/// samples are given, `x` is not monotonic or any abscissa is not positive.
+pub fn trapezoid_log_x_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_log_x(y, x)
+}
+
+fn trapezoid_log_x<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    if validate(y, x).is_err() || !x.iter().all(|&v| v > T::zero()) {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    let integral = pairwise_fold(0, y.len() - 1, &|acc, i| {
+        // the logarithm of the ratio keeps full precision for closely spaced samples
+        let du = (x[i + 1] / x[i]).ln();
+        fmla(du * T::HALF, fmla(y[i], x[i], y[i + 1] * x[i + 1]), acc)
+    });
+    if grid.descending { -integral } else { integral }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_log_x() {
+        let x: Vec<f64> = (0..=20).map(|i| 10f64.powf(i as f64 / 10.)).collect();
+        let y: Vec<f64> = x.iter().map(|x| 1. / x).collect();
+        let exact = 100f64.ln();
+        let log_err = (trapezoid_log_x_f64(&y, &x) - exact).abs();
+        let linear_err = (trapezoid_f64(&y, &x) - exact).abs();
+        assert!(log_err < 1e-13, "{log_err}");
+        assert!(linear_err > 1e-2, "{linear_err}");
+
+        assert!(trapezoid_log_x_f64(&[1., 1.], &[0., 1.]).is_nan());
+        assert!(trapezoid_log_x_f32(&[1., 1., 1.], &[1., 2., 1.5]).is_nan());
+    }
+}
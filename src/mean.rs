@@ -0,0 +1,211 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::trapezoid::{classify, trapezoid, trapezoid_even, validate};
+use num_traits::AsPrimitive;
+
+/// Average value of the sampled function over `[x[0], x[n - 1]]`.
+///
+/// The integral from [trapezoid_f64](crate::trapezoid_f64) divided by the width of the
+/// interval, descending abscissas give the same mean as ascending ones.
+///
+/// # Returns
+/// The mean value, or NaN if the input is invalid or all abscissas are equal.
+pub fn trapezoid_mean_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_mean(y, x)
+}
+
+/// Average value of the sampled function over `[x[0], x[n - 1]]`.
+///
+/// The integral from [trapezoid_f32](crate::trapezoid_f32) divided by the width of the
+/// interval, descending abscissas give the same mean as ascending ones.
+///
+/// # Returns
+/// The mean value, or NaN if the input is invalid or all abscissas are equal.
+pub fn trapezoid_mean_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_mean(y, x)
+}
+
+/// Average value of evenly spaced samples, the integral divided by `(n - 1) * dx`.
+///
+/// # Returns
+/// The mean value, or NaN if `y` has fewer than two samples or `dx <= 0`.
+pub fn trapezoid_even_mean_f64(y: &[f64], dx: f64) -> f64 {
+    trapezoid_even_mean(y, dx)
+}
+
+/// Average value of evenly spaced samples, the integral divided by `(n - 1) * dx`.
+///
+/// # Returns
+/// The mean value, or NaN if `y` has fewer than two samples or `dx <= 0`.
+pub fn trapezoid_even_mean_f32(y: &[f32], dx: f32) -> f32 {
+    trapezoid_even_mean(y, dx)
+}
+
+/// Abscissa of the centroid of the area under the samples, `∫ x y dx / ∫ y dx`.
+///
+/// Both integrals use the trapezoidal rule and are accumulated in one pass over the
+/// segments. With negative samples the integrals are signed, so areas below the axis
+/// pull the centroid away from them and partially cancelling areas can place it
+/// outside `[x[0], x[n - 1]]`. `x` follows the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The centroid, or NaN if the input is invalid or `∫ y dx` is zero.
+pub fn trapezoid_centroid_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_centroid(y, x)
+}
+
+/// Abscissa of the centroid of the area under the samples, `∫ x y dx / ∫ y dx`.
+///
+/// Both integrals use the trapezoidal rule and are accumulated in one pass over the
+/// segments. With negative samples the integrals are signed, so areas below the axis
+/// pull the centroid away from them and partially cancelling areas can place it
+/// outside `[x[0], x[n - 1]]`. `x` follows the rules of [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The centroid, or NaN if the input is invalid or `∫ y dx` is zero.
+pub fn trapezoid_centroid_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_centroid(y, x)
+}
+
+/// Rescales `y` in place so that its trapezoidal integral over `x` is one.
+///
+/// Every sample is divided by the integral from [trapezoid_f64](crate::trapezoid_f64),
+/// turning a non-negative `y` into a probability density on the grid.
+///
+/// # Returns
+/// The integral before scaling, the normalization constant. NaN if the input is
+/// invalid or the integral is zero or not finite, `y` is then left unchanged.
+pub fn normalize_density_f64(y: &mut [f64], x: &[f64]) -> f64 {
+    normalize_density(y, x)
+}
+
+/// Rescales `y` in place so that its trapezoidal integral over `x` is one.
+///
+/// Every sample is divided by the integral from [trapezoid_f32](crate::trapezoid_f32),
+/// turning a non-negative `y` into a probability density on the grid.
+///
+/// # Returns
+/// The integral before scaling, the normalization constant. NaN if the input is
+/// invalid or the integral is zero or not finite, `y` is then left unchanged.
+pub fn normalize_density_f32(y: &mut [f32], x: &[f32]) -> f32 {
+    normalize_density(y, x)
+}
+
+fn normalize_density<T: TrapezSample>(y: &mut [T], x: &[T]) -> T {
+    let integral = trapezoid(y, x);
+    if !integral.is_finite() || integral == T::zero() {
+        return T::nan();
+    }
+    for v in y.iter_mut() {
+        *v = *v / integral;
+    }
+    integral
+}
+
+fn trapezoid_centroid<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    if validate(y, x).is_err() || classify(x).is_err() {
+        return T::nan();
+    }
+    // the orientation of x flips both integrals, so the ratio needs no sign fix
+    let (mut moment, mut area) = (T::zero(), T::zero());
+    for (y, x) in y.windows(2).zip(x.windows(2)) {
+        let half_dx = (x[1] - x[0]) * T::HALF;
+        moment = fmla(half_dx, fmla(x[0], y[0], x[1] * y[1]), moment);
+        area = fmla(half_dx, y[0] + y[1], area);
+    }
+    if area == T::zero() {
+        return T::nan();
+    }
+    moment / area
+}
+
+fn trapezoid_mean<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    let integral = trapezoid(y, x);
+    if integral.is_nan() {
+        return integral;
+    }
+    let width = (x[x.len() - 1] - x[0]).abs();
+    if width == T::zero() {
+        return T::nan();
+    }
+    integral / width
+}
+
+fn trapezoid_even_mean<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let integral = trapezoid_even(y, dx);
+    if integral.is_nan() {
+        return integral;
+    }
+    let intervals: T = ((y.len() - 1) as f64).as_();
+    integral / (intervals * dx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_mean() {
+        let y = [3.25; 5];
+        assert_eq!(trapezoid_mean_f64(&y, &[0., 0.5, 1.75, 2., 4.]), 3.25);
+        assert_eq!(trapezoid_mean_f64(&y, &[4., 2., 1.75, 0.5, 0.]), 3.25);
+        assert_eq!(trapezoid_even_mean_f64(&y, 0.25), 3.25);
+        assert_eq!(trapezoid_even_mean_f32(&[3.25; 5], 8.), 3.25);
+        assert!(trapezoid_mean_f64(&y, &[1.; 5]).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_centroid() {
+        // triangular pulse symmetric about x = 2
+        let x = [0., 0.5, 1., 1.5, 2., 2.5, 3., 3.5, 4.];
+        let y = [0., 0., 1., 2., 3., 2., 1., 0., 0.];
+        assert_eq!(trapezoid_centroid_f64(&y, &x), 2.);
+        let rev_x: Vec<f64> = x.iter().rev().copied().collect();
+        assert_eq!(trapezoid_centroid_f64(&y, &rev_x), 2.);
+        assert!((trapezoid_centroid_f32(&[1., 1.], &[1., 3.]) - 2.).abs() < 1e-6);
+        assert!(trapezoid_centroid_f64(&[1., -1.], &[0., 1.]).is_nan());
+    }
+
+    #[test]
+    fn test_normalize_density() {
+        let x = [0., 0.5, 1.5, 2., 3.];
+        let mut y = [0., 2., 3., 1., 0.];
+        let constant = normalize_density_f64(&mut y, &x);
+        assert_eq!(constant, 4.5);
+        assert!((crate::trapezoid_f64(&y, &x) - 1.).abs() < 1e-15);
+        let mut flat = [1., -1.];
+        assert!(normalize_density_f32(&mut flat, &[0., 1.]).is_nan());
+        assert_eq!(flat, [1., -1.]);
+    }
+}
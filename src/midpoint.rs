@@ -0,0 +1,121 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::{pairwise_fold, sum};
+
+/// Midpoint (rectangle) rule for evenly spaced intervals.
+/// `y` = function values sampled at the interval midpoints
+/// `dx` = width of every interval
+///
+/// Unlike the trapezoid rule `y` holds one sample per interval rather than one per edge.
+///
+/// Returns NaN if `y` is empty or `dx <= 0`.
+pub fn midpoint_even_f64(y: &[f64], dx: f64) -> f64 {
+    midpoint_even(y, dx)
+}
+
+/// Midpoint (rectangle) rule for evenly spaced intervals.
+/// `y` = function values sampled at the interval midpoints
+/// `dx` = width of every interval
+///
+/// Unlike the trapezoid rule `y` holds one sample per interval rather than one per edge.
+///
+/// Returns NaN if `y` is empty or `dx <= 0`.
+pub fn midpoint_even_f32(y: &[f32], dx: f32) -> f32 {
+    midpoint_even(y, dx)
+}
+
+/// Midpoint (rectangle) rule for arbitrary intervals.
+///
+/// Unlike the trapezoid rule, where `x` and `y` share a length, here `x` holds the
+/// `n + 1` interval edges and `y` the `n` samples taken at the interval midpoints.
+///
+/// # Arguments
+/// * `y` - The function values at the interval midpoints.
+/// * `x` - The interval edges, `y.len() + 1` elements.
+///
+/// # Returns
+/// The approximate definite integral, or NaN if `y` is empty or `x.len() != y.len() + 1`.
+pub fn midpoint_f64(y: &[f64], x: &[f64]) -> f64 {
+    midpoint(y, x)
+}
+
+/// Midpoint (rectangle) rule for arbitrary intervals.
+///
+/// Unlike the trapezoid rule, where `x` and `y` share a length, here `x` holds the
+/// `n + 1` interval edges and `y` the `n` samples taken at the interval midpoints.
+///
+/// # Arguments
+/// * `y` - The function values at the interval midpoints.
+/// * `x` - The interval edges, `y.len() + 1` elements.
+///
+/// # Returns
+/// The approximate definite integral, or NaN if `y` is empty or `x.len() != y.len() + 1`.
+pub fn midpoint_f32(y: &[f32], x: &[f32]) -> f32 {
+    midpoint(y, x)
+}
+
+fn midpoint_even<T: TrapezSample>(y: &[T], dx: T) -> T {
+    if y.is_empty() || dx <= T::zero() {
+        return T::nan();
+    }
+    dx * sum(y)
+}
+
+fn midpoint<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    let n = y.len();
+    if n == 0 || x.len() != n + 1 {
+        return T::nan();
+    }
+    pairwise_fold(0, n, &|acc, i| fmla(y[i], x[i + 1] - x[i], acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_midpoint_linear() {
+        let f = |x: f64| 3. * x - 2.;
+        // [0, 2] in 8 intervals
+        let y: Vec<f64> = (0..8).map(|i| f((i as f64 + 0.5) * 0.25)).collect();
+        assert!((midpoint_even_f64(&y, 0.25) - 2.).abs() < 1e-12);
+        let x = [0., 0.1, 0.5, 1.2, 2.];
+        let y: Vec<f64> = x.windows(2).map(|w| f(0.5 * (w[0] + w[1]))).collect();
+        assert!((midpoint_f64(&y, &x) - 2.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_midpoint_invalid() {
+        assert!(midpoint_even_f32(&[], 0.1).is_nan());
+        assert!(midpoint_even_f32(&[1.], -0.1).is_nan());
+        assert!(midpoint_f64(&[1., 2.], &[0., 1.]).is_nan());
+    }
+}
@@ -26,37 +26,93 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use num_traits::MulAdd;
-use std::ops::{Add, Mul};
+/// Whether the integration kernels use a fused multiply-add.
+///
+/// Decided at compile time: `true` on aarch64 and on x86 built with the `fma` target
+/// feature (e.g. `-C target-cpu=native` on a CPU supporting it), always `false` with
+/// the `deterministic` or `plain_arithmetic` features. Fused operations round once, so
+/// results can differ in the last bits from a build where this is `false` and
+/// `a * b + c` is evaluated with two roundings. Software emulation of a fused operation
+/// is never used, as it would be far slower than the plain expression.
+pub const FUSED_MULTIPLY_ADD: bool = cfg!(all(
+    not(feature = "deterministic"),
+    not(feature = "plain_arithmetic"),
+    any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    )
+));
 
-#[cfg(any(
-    all(
-        any(target_arch = "x86", target_arch = "x86_64"),
-        target_feature = "fma"
-    ),
-    target_arch = "aarch64"
+#[cfg(all(
+    not(feature = "deterministic"),
+    not(feature = "plain_arithmetic"),
+    any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    )
 ))]
 #[inline(always)]
-pub(crate) fn fmla<T: Copy + Mul<T, Output = T> + Add<T, Output = T> + MulAdd<T, Output = T>>(
-    a: T,
-    b: T,
-    c: T,
-) -> T {
-    MulAdd::mul_add(a, b, c)
+pub(crate) fn fmla<T: num_traits::Float>(a: T, b: T, c: T) -> T {
+    a.mul_add(b, c)
 }
 
-#[cfg(not(any(
-    all(
-        any(target_arch = "x86", target_arch = "x86_64"),
-        target_feature = "fma"
-    ),
-    target_arch = "aarch64"
+#[cfg(not(all(
+    not(feature = "deterministic"),
+    not(feature = "plain_arithmetic"),
+    any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "fma"
+        ),
+        target_arch = "aarch64"
+    )
 )))]
 #[inline(always)]
-pub(crate) fn fmla<T: Copy + Mul<T, Output = T> + Add<T, Output = T> + MulAdd<T, Output = T>>(
-    a: T,
-    b: T,
-    c: T,
-) -> T {
-    MulAdd::mul_add(a, b, c)
+pub(crate) fn fmla<T: num_traits::Float>(a: T, b: T, c: T) -> T {
+    a * b + c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmla_contract() {
+        // a * b = 1 - 2^-54 exactly, which rounds to 1 before the addition
+        let a = 1. + f64::powi(2., -27);
+        let b = 1. - f64::powi(2., -27);
+        let expected = if FUSED_MULTIPLY_ADD {
+            -f64::powi(2., -54)
+        } else {
+            0.
+        };
+        assert_eq!(fmla(a, b, -1.), expected);
+    }
+
+    #[test]
+    fn test_fmla_paths_agree() {
+        // the inputs of `test_fmla_contract` over a grid of offsets, a * b = 1 - (k * 2^-27)^2
+        // is inexact for odd k, so two roundings lose up to 2^-54 per term
+        let n = 100;
+        let step = f64::powi(2., -27);
+        let residuals = |mla: &dyn Fn(f64, f64, f64) -> f64| {
+            (1..=n)
+                .map(|k| mla(1. + k as f64 * step, 1. - k as f64 * step, -1.))
+                .sum::<f64>()
+        };
+        let exact = -((n * (n + 1) * (2 * n + 1) / 6) as f64) * step * step;
+        let plain = residuals(&|a, b, c| a * b + c);
+        let fused = residuals(&|a, b, c| a.mul_add(b, c));
+        assert_eq!(fused, exact);
+        assert_ne!(plain, fused);
+        assert!((plain - fused).abs() <= n as f64 * step * step);
+        let expected = if FUSED_MULTIPLY_ADD { fused } else { plain };
+        assert_eq!(residuals(&|a, b, c| fmla(a, b, c)), expected);
+    }
 }
@@ -0,0 +1,191 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{add_segment, classify, trapezoid, validate};
+
+/// How segments touching a NaN sample are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NanPolicy {
+    /// Segments with a NaN endpoint contribute nothing.
+    #[default]
+    Skip,
+    /// Runs of NaN samples are bridged by linear interpolation between the nearest
+    /// valid neighbours, which is a single trapezoid spanning the gap.
+    Interpolate,
+}
+
+/// Trapezoidal rule that tolerates NaN dropouts in `y`.
+///
+/// Interior NaN samples are handled according to `policy`. Leading and trailing NaNs
+/// have a valid neighbour on one side only, so with either policy they are dropped
+/// and the integral covers the range between the first and the last valid sample.
+/// `x` must be monotonic and free of NaNs, as in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The approximate integral, or NaN if the input is invalid
+/// or `y` has fewer than two valid samples.
+pub fn trapezoid_nan_ignore_f64(y: &[f64], x: &[f64], policy: NanPolicy) -> f64 {
+    trapezoid_nan_ignore(y, x, policy)
+}
+
+/// Trapezoidal rule that tolerates NaN dropouts in `y`.
+///
+/// Interior NaN samples are handled according to `policy`. Leading and trailing NaNs
+/// have a valid neighbour on one side only, so with either policy they are dropped
+/// and the integral covers the range between the first and the last valid sample.
+/// `x` must be monotonic and free of NaNs, as in [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The approximate integral, or NaN if the input is invalid
+/// or `y` has fewer than two valid samples.
+pub fn trapezoid_nan_ignore_f32(y: &[f32], x: &[f32], policy: NanPolicy) -> f32 {
+    trapezoid_nan_ignore(y, x, policy)
+}
+
+/// Integrates the leading run of finite samples and reports where it ends.
+///
+/// `k` counts the samples before the first index where `y` or `x` is NaN or infinite,
+/// the integral then covers `y[0..k]` over `x[0..k]` with the rules of
+/// [trapezoid_f64](crate::trapezoid_f64), so padding at the end of a stream is cut off.
+///
+/// # Returns
+/// `(integral, k)`, the integral is NaN if `k < 2`, the lengths differ
+/// or the prefix of `x` is not monotonic.
+pub fn trapezoid_finite_prefix_f64(y: &[f64], x: &[f64]) -> (f64, usize) {
+    trapezoid_finite_prefix(y, x)
+}
+
+/// Integrates the leading run of finite samples and reports where it ends.
+///
+/// `k` counts the samples before the first index where `y` or `x` is NaN or infinite,
+/// the integral then covers `y[0..k]` over `x[0..k]` with the rules of
+/// [trapezoid_f32](crate::trapezoid_f32), so padding at the end of a stream is cut off.
+///
+/// # Returns
+/// `(integral, k)`, the integral is NaN if `k < 2`, the lengths differ
+/// or the prefix of `x` is not monotonic.
+pub fn trapezoid_finite_prefix_f32(y: &[f32], x: &[f32]) -> (f32, usize) {
+    trapezoid_finite_prefix(y, x)
+}
+
+fn trapezoid_finite_prefix<T: TrapezSample>(y: &[T], x: &[T]) -> (T, usize) {
+    if y.len() != x.len() {
+        return (T::nan(), 0);
+    }
+    let k = y
+        .iter()
+        .zip(x.iter())
+        .position(|(y, x)| !y.is_finite() || !x.is_finite())
+        .unwrap_or(y.len());
+    (trapezoid(&y[..k], &x[..k]), k)
+}
+
+pub(crate) fn trapezoid_nan_ignore<T: TrapezSample>(y: &[T], x: &[T], policy: NanPolicy) -> T {
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    if y.iter().filter(|v| !v.is_nan()).nth(1).is_none() {
+        return T::nan();
+    }
+    let integral = nan_segment_sum(y, x, policy);
+    if grid.descending { -integral } else { integral }
+}
+
+/// Signed sum of the segments kept by `policy`, reduced pairwise.
+pub(crate) fn nan_segment_sum<T: TrapezSample>(y: &[T], x: &[T], policy: NanPolicy) -> T {
+    pairwise_fold(1, y.len(), &|acc, i| match segment_start(y, policy, i) {
+        Some(j) => add_segment(acc, &[y[j], y[i]], &[x[j], x[i]]),
+        None => acc,
+    })
+}
+
+/// Index of the sample that starts the segment ending at `i > 0`, or `None` if `y[i]`
+/// is NaN or `policy` drops the segment. A run of NaNs before `i` is walked back only
+/// from its right neighbour, so visiting every `i` stays linear.
+pub(crate) fn segment_start<T: TrapezSample>(
+    y: &[T],
+    policy: NanPolicy,
+    i: usize,
+) -> Option<usize> {
+    if y[i].is_nan() {
+        return None;
+    }
+    match policy {
+        NanPolicy::Skip => (!y[i - 1].is_nan()).then_some(i - 1),
+        NanPolicy::Interpolate => (0..i).rev().find(|&j| !y[j].is_nan()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid_finite_prefix() {
+        let y = [5., 6., 1., 4., f64::NAN, 2., 3.];
+        let x = [1., 2., 4., 6., 7., 9., 10.];
+        let (integral, k) = trapezoid_finite_prefix_f64(&y, &x);
+        assert_eq!(k, 4);
+        assert_eq!(integral, crate::trapezoid_f64(&y[..4], &x[..4]));
+        assert_eq!(trapezoid_finite_prefix_f32(&[1., 3.], &[0., 2.]), (4., 2));
+        let (integral, k) = trapezoid_finite_prefix_f64(&[1., 2.], &[0., f64::INFINITY]);
+        assert!(integral.is_nan());
+        assert_eq!(k, 1);
+    }
+
+    #[test]
+    fn test_trapezoid_nan_single() {
+        let y = [5., 6., f64::NAN, 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        // segments 2..4 and 4..6 are dropped: 5.5 + 5 + 8
+        assert_eq!(trapezoid_nan_ignore_f64(&y, &x, NanPolicy::Skip), 18.5);
+        // the gap becomes one trapezoid between x = 2 and x = 6
+        assert_eq!(
+            trapezoid_nan_ignore_f64(&y, &x, NanPolicy::Interpolate),
+            38.5
+        );
+    }
+
+    #[test]
+    fn test_trapezoid_nan_run() {
+        let nan = f32::NAN;
+        let y = [nan, 1., 1., nan, nan, nan, 3., 1., nan];
+        let x = [0., 1., 2., 3., 4., 5., 6., 7., 8.];
+        assert_eq!(trapezoid_nan_ignore_f32(&y, &x, NanPolicy::Skip), 3.);
+        assert_eq!(
+            trapezoid_nan_ignore_f32(&y, &x, NanPolicy::Interpolate),
+            11.
+        );
+        assert!(trapezoid_nan_ignore_f32(&[nan, 1., nan], &[0., 1., 2.], NanPolicy::Skip).is_nan());
+    }
+}
@@ -0,0 +1,101 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::arch::aarch64::*;
+
+/// Sums `v` using four independent 2-lane accumulators.
+#[target_feature(enable = "neon")]
+pub(crate) fn sum_f64_neon(v: &[f64]) -> f64 {
+    let mut acc0 = vdupq_n_f64(0.);
+    let mut acc1 = vdupq_n_f64(0.);
+    let mut acc2 = vdupq_n_f64(0.);
+    let mut acc3 = vdupq_n_f64(0.);
+
+    let chunks = v.chunks_exact(8);
+    let rem = chunks.remainder();
+    for chunk in chunks {
+        unsafe {
+            acc0 = vaddq_f64(acc0, vld1q_f64(chunk.as_ptr()));
+            acc1 = vaddq_f64(acc1, vld1q_f64(chunk.as_ptr().add(2)));
+            acc2 = vaddq_f64(acc2, vld1q_f64(chunk.as_ptr().add(4)));
+            acc3 = vaddq_f64(acc3, vld1q_f64(chunk.as_ptr().add(6)));
+        }
+    }
+
+    let acc = vaddq_f64(vaddq_f64(acc0, acc1), vaddq_f64(acc2, acc3));
+    let mut sum = vaddvq_f64(acc);
+    for &v in rem.iter() {
+        sum += v;
+    }
+    sum
+}
+
+/// Sums `v` using four independent 4-lane accumulators.
+#[target_feature(enable = "neon")]
+pub(crate) fn sum_f32_neon(v: &[f32]) -> f32 {
+    let mut acc0 = vdupq_n_f32(0.);
+    let mut acc1 = vdupq_n_f32(0.);
+    let mut acc2 = vdupq_n_f32(0.);
+    let mut acc3 = vdupq_n_f32(0.);
+
+    let chunks = v.chunks_exact(16);
+    let rem = chunks.remainder();
+    for chunk in chunks {
+        unsafe {
+            acc0 = vaddq_f32(acc0, vld1q_f32(chunk.as_ptr()));
+            acc1 = vaddq_f32(acc1, vld1q_f32(chunk.as_ptr().add(4)));
+            acc2 = vaddq_f32(acc2, vld1q_f32(chunk.as_ptr().add(8)));
+            acc3 = vaddq_f32(acc3, vld1q_f32(chunk.as_ptr().add(12)));
+        }
+    }
+
+    let acc = vaddq_f32(vaddq_f32(acc0, acc1), vaddq_f32(acc2, acc3));
+    let mut sum = vaddvq_f32(acc);
+    for &v in rem.iter() {
+        sum += v;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrapezSample;
+    use crate::sum::pairwise_sum;
+
+    #[test]
+    fn test_sum_neon() {
+        let v: Vec<f64> = (0..10_007).map(|i| (i as f64 * 0.37).sin()).collect();
+        let scalar = pairwise_sum(&v);
+        let simd = unsafe { sum_f64_neon(&v) };
+        assert!((simd - scalar).abs() <= f64::TOLERANCE * scalar.abs().max(1.));
+        let v: Vec<f32> = v.iter().map(|&x| x as f32).collect();
+        let simd = unsafe { sum_f32_neon(&v) };
+        assert!((simd - pairwise_sum(&v)).abs() <= 1e-2);
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use num_traits::AsPrimitive;
+
+/// Composite Boole's rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Every panel of four intervals is weighted by `(7, 32, 12, 32, 7) * 2 * dx / 45`,
+/// which integrates polynomials up to degree five exactly.
+///
+/// Returns NaN unless `y` has `4k + 1` samples (`k >= 1`) and `dx > 0`,
+/// there is no fallback to a lower order rule for the trailing samples.
+pub fn boole_even_f64(y: &[f64], dx: f64) -> f64 {
+    boole_even(y, dx)
+}
+
+/// Composite Boole's rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Every panel of four intervals is weighted by `(7, 32, 12, 32, 7) * 2 * dx / 45`,
+/// which integrates polynomials up to degree five exactly.
+///
+/// Returns NaN unless `y` has `4k + 1` samples (`k >= 1`) and `dx > 0`,
+/// there is no fallback to a lower order rule for the trailing samples.
+pub fn boole_even_f32(y: &[f32], dx: f32) -> f32 {
+    boole_even(y, dx)
+}
+
+pub(crate) fn boole_even<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 5 || !(n - 1).is_multiple_of(4) || dx <= T::zero() {
+        return T::nan();
+    }
+
+    // panel boundaries get weight 7, shared interior boundaries count twice
+    let mut ends = T::zero();
+    let mut odd = T::zero();
+    let mut mid = T::zero();
+    for panel in y[..n - 1].chunks_exact(4) {
+        ends += panel[0];
+        odd += panel[1] + panel[3];
+        mid += panel[2];
+    }
+    ends = 2f64.as_() * ends - y[0] + y[n - 1];
+
+    let inner = fmla(7f64.as_(), ends, fmla(32f64.as_(), odd, 12f64.as_() * mid));
+    dx * (2. / 45f64).as_() * inner
+}
+
+/// Composite Weddle's rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Every panel of six intervals is weighted by `(1, 5, 1, 6, 1, 5, 1) * 3 * dx / 10`,
+/// which integrates polynomials up to degree five exactly.
+///
+/// Returns NaN unless `y` has `6k + 1` samples (`k >= 1`) and `dx > 0`,
+/// there is no fallback to a lower order rule for the trailing samples.
+pub fn weddle_even_f64(y: &[f64], dx: f64) -> f64 {
+    weddle_even(y, dx)
+}
+
+/// Composite Weddle's rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Every panel of six intervals is weighted by `(1, 5, 1, 6, 1, 5, 1) * 3 * dx / 10`,
+/// which integrates polynomials up to degree five exactly.
+///
+/// Returns NaN unless `y` has `6k + 1` samples (`k >= 1`) and `dx > 0`,
+/// there is no fallback to a lower order rule for the trailing samples.
+pub fn weddle_even_f32(y: &[f32], dx: f32) -> f32 {
+    weddle_even(y, dx)
+}
+
+fn weddle_even<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 7 || !(n - 1).is_multiple_of(6) || dx <= T::zero() {
+        return T::nan();
+    }
+
+    // panel boundaries get weight 1, shared interior boundaries count twice
+    let mut ends = T::zero();
+    let mut ones = T::zero();
+    let mut fives = T::zero();
+    let mut mid = T::zero();
+    for panel in y[..n - 1].chunks_exact(6) {
+        ends += panel[0];
+        ones += panel[2] + panel[4];
+        fives += panel[1] + panel[5];
+        mid += panel[3];
+    }
+    ends = 2f64.as_() * ends - y[0] + y[n - 1];
+
+    let inner = fmla(5f64.as_(), fives, fmla(6f64.as_(), mid, ends + ones));
+    dx * (3. / 10f64).as_() * inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boole_quartic() {
+        // x^4 over [0, 2] in 8 intervals
+        let y: Vec<f64> = (0..9).map(|i| (i as f64 * 0.25).powi(4)).collect();
+        assert!((boole_even_f64(&y, 0.25) - 6.4).abs() < 1e-14);
+        // x^5 - x over [0, 1] in 4 intervals
+        let y: Vec<f32> = (0..5)
+            .map(|i| (i as f32 * 0.25).powi(5) - i as f32 * 0.25)
+            .collect();
+        assert!((boole_even_f32(&y, 0.25) - (1. / 6. - 0.5)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_boole_invalid() {
+        assert!(boole_even_f64(&[1.; 6], 0.1).is_nan());
+        assert!(boole_even_f64(&[1.; 3], 0.1).is_nan());
+        assert!(boole_even_f64(&[1.; 5], 0.).is_nan());
+    }
+
+    #[test]
+    fn test_weddle_quintic() {
+        // x^5 - 2 x^3 over [0, 3] in 12 intervals
+        let f = |x: f64| x.powi(5) - 2. * x.powi(3);
+        let y: Vec<f64> = (0..13).map(|i| f(i as f64 * 0.25)).collect();
+        let exact = 3f64.powi(6) / 6. - 3f64.powi(4) / 2.;
+        assert!((weddle_even_f64(&y, 0.25) - exact).abs() < 1e-12);
+        assert!(weddle_even_f32(&[1.; 8], 0.1).is_nan());
+        assert!(weddle_even_f64(&[1.; 7], -1.).is_nan());
+    }
+}
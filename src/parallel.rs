@@ -0,0 +1,103 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::sum;
+use rayon::prelude::*;
+
+/// Number of samples each rayon task sums serially.
+const CHUNK: usize = 1 << 16;
+
+/// Parallel trapezoidal integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// The interior is split into chunks that are summed on the rayon thread pool,
+/// the partial sums are then combined and the endpoints added with weight `0.5`.
+/// Because the additions are reassociated the result may differ from
+/// [trapezoid_even_f64](crate::trapezoid_even_f64) in the last ULP, with the
+/// `deterministic` feature it is still identical across runs and thread counts.
+///
+/// Returns NaN if `y` has fewer than two samples or `dx <= 0`.
+pub fn par_trapezoid_even_f64(y: &[f64], dx: f64) -> f64 {
+    par_trapezoid_even(y, dx)
+}
+
+/// Parallel trapezoidal integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// The interior is split into chunks that are summed on the rayon thread pool,
+/// the partial sums are then combined and the endpoints added with weight `0.5`.
+/// Because the additions are reassociated the result may differ from
+/// [trapezoid_even_f32](crate::trapezoid_even_f32) in the last ULP, with the
+/// `deterministic` feature it is still identical across runs and thread counts.
+///
+/// Returns NaN if `y` has fewer than two samples or `dx <= 0`.
+pub fn par_trapezoid_even_f32(y: &[f32], dx: f32) -> f32 {
+    par_trapezoid_even(y, dx)
+}
+
+fn par_trapezoid_even<T: TrapezSample + Send + Sync>(y: &[T], dx: T) -> T {
+    let n = y.len();
+    if n < 2 || dx <= T::zero() {
+        return T::nan();
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    let interior_sum = y[1..n - 1]
+        .par_chunks(CHUNK)
+        .map(sum)
+        .reduce(T::zero, |a, b| a + b);
+    // the shape of the rayon reduction depends on work stealing, so the chunk sums
+    // are combined in index order instead
+    #[cfg(feature = "deterministic")]
+    let interior_sum = sum(&y[1..n - 1].par_chunks(CHUNK).map(sum).collect::<Vec<T>>());
+
+    dx * fmla(T::HALF, y[0] + y[n - 1], interior_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TrapezSample, trapezoid_even_f64};
+
+    #[test]
+    fn test_par_trapezoid_even() {
+        let y: Vec<f64> = (0..3_000_000).map(|i| (i as f64 * 1e-5).sin()).collect();
+        let serial = trapezoid_even_f64(&y, 1e-5);
+        let parallel = par_trapezoid_even_f64(&y, 1e-5);
+        assert!((serial - parallel).abs() <= f64::TOLERANCE * serial.abs());
+        assert_eq!(
+            par_trapezoid_even_f64(&[5., 6., 1., 4., 6., 2.], 0.003),
+            0.0615
+        );
+        assert!(par_trapezoid_even_f32(&[1.], 0.1).is_nan());
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::trapezoid::trapezoid;
+use alloc::vec::Vec;
+
+/// Integrates independent runs of samples split at the indices in `breaks`.
+///
+/// The sample at a break ends one run and starts the next, so `breaks = [b0, b1]`
+/// integrates `0..=b0`, `b0..=b1` and `b1..=n - 1` separately with
+/// [trapezoid_f64](crate::trapezoid_f64). Every segment belongs to exactly one run,
+/// on a grid that is monotonic as a whole the integrals add up to the integral over
+/// all samples.
+///
+/// # Returns
+/// One integral per run, `breaks.len() + 1` in total.
+/// Empty if there are fewer than two samples, the lengths of `y` and `x` differ,
+/// or `breaks` is not strictly increasing within `1..n - 1`.
+pub fn trapezoid_segments_f64(y: &[f64], x: &[f64], breaks: &[usize]) -> Vec<f64> {
+    trapezoid_segments(y, x, breaks)
+}
+
+/// Integrates independent runs of samples split at the indices in `breaks`.
+///
+/// The sample at a break ends one run and starts the next, so `breaks = [b0, b1]`
+/// integrates `0..=b0`, `b0..=b1` and `b1..=n - 1` separately with
+/// [trapezoid_f32](crate::trapezoid_f32). Every segment belongs to exactly one run,
+/// on a grid that is monotonic as a whole the integrals add up to the integral over
+/// all samples.
+///
+/// # Returns
+/// One integral per run, `breaks.len() + 1` in total.
+/// Empty if there are fewer than two samples, the lengths of `y` and `x` differ,
+/// or `breaks` is not strictly increasing within `1..n - 1`.
+pub fn trapezoid_segments_f32(y: &[f32], x: &[f32], breaks: &[usize]) -> Vec<f32> {
+    trapezoid_segments(y, x, breaks)
+}
+
+fn trapezoid_segments<T: TrapezSample>(y: &[T], x: &[T], breaks: &[usize]) -> Vec<T> {
+    let n = y.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let sorted = breaks.windows(2).all(|w| w[0] < w[1]);
+    let in_range =
+        breaks.first().is_none_or(|&b| b > 0) && breaks.last().is_none_or(|&b| b + 1 < n);
+    if x.len() != n || !sorted || !in_range {
+        return Vec::new();
+    }
+    let starts = core::iter::once(0).chain(breaks.iter().copied());
+    let ends = breaks.iter().copied().chain(core::iter::once(n - 1));
+    starts
+        .zip(ends)
+        .map(|(start, end)| trapezoid(&y[start..=end], &x[start..=end]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_segments() {
+        let x: Vec<f64> = (0..10).map(|i| i as f64 * 0.5).collect();
+        let y: Vec<f64> = x.iter().map(|x| x * x).collect();
+        let parts = trapezoid_segments_f64(&y, &x, &[5]);
+        assert_eq!(parts.len(), 2);
+        let full = trapezoid_f64(&y, &x);
+        assert!((parts[0] + parts[1] - full).abs() < 1e-12);
+
+        let runs = trapezoid_segments_f32(&[1., 1., 2., 2., 2.], &[0., 1., 5., 6., 7.], &[2, 3]);
+        assert_eq!(runs, [7., 2., 2.]);
+        assert!(trapezoid_segments_f64(&y, &x, &[9]).is_empty());
+        assert!(trapezoid_segments_f64(&[], &[], &[]).is_empty());
+        assert!(trapezoid_segments_f64(&y, &x, &[5, 3]).is_empty());
+        assert!(trapezoid_segments_f64(&y, &x, &[10]).is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! The commonly used functions and types in one place.
+//!
+//! ```
+//! use trapez_integrate::prelude::*;
+//!
+//! let y = [1., 2., 3.];
+//! assert_eq!(trapezoid_f64(&y, &[0., 1., 2.]), 4.);
+//! assert_eq!(try_trapezoid_even_f64(&y, 1.), Ok(4.));
+//! assert_eq!(Simpson.integrate(&y, &[0., 1., 2.]), 4.);
+//! ```
+pub use crate::TrapezSample;
+#[cfg(feature = "alloc")]
+pub use crate::cumulative::{cumtrapz_f32, cumtrapz_f64};
+pub use crate::cumulative::{cumtrapz_into_f32, cumtrapz_into_f64};
+pub use crate::detailed::{TrapezoidResult, trapezoid_detailed_f32, trapezoid_detailed_f64};
+pub use crate::error::IntegrateError;
+pub use crate::function::{
+    adaptive_trapezoid_f32, adaptive_trapezoid_f64, trapezoid_fn_f32, trapezoid_fn_f64,
+};
+pub use crate::integrator::{Accum, Integrator};
+pub use crate::nan::{NanPolicy, trapezoid_nan_ignore_f32, trapezoid_nan_ignore_f64};
+pub use crate::quadrature::{Boole, Method, Quadrature, Simpson, Trapezoid, integrate_f64};
+pub use crate::simpson::{simpson_even_f32, simpson_even_f64, simpson_f32, simpson_f64};
+pub use crate::streaming::StreamingTrapezoid;
+pub use crate::trapezoid::{
+    trapezoid, trapezoid_even, trapezoid_even_f32, trapezoid_even_f64, trapezoid_f32,
+    trapezoid_f64, trapezoid_signed_f32, trapezoid_signed_f64, try_trapezoid_even_f32,
+    try_trapezoid_even_f64, try_trapezoid_f32, try_trapezoid_f64,
+};
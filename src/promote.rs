@@ -0,0 +1,69 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::classify_iter;
+
+/// Trapezoid rule over samples that are widened to the accumulator type `A` as they are
+/// read, so narrow inputs get the accuracy of `A` without converting the whole array.
+///
+/// Follows the same rules as [trapezoid_f64](crate::trapezoid_f64): the abscissas must be
+/// monotonic, descending abscissas give the integral of the reversed data.
+pub(crate) fn try_trapezoid_promoted<Y: Copy, X: Copy, A: TrapezSample>(
+    y: &[Y],
+    x: &[X],
+    fy: impl Fn(Y) -> A,
+    fx: impl Fn(X) -> A,
+) -> Result<A, IntegrateError> {
+    let n = y.len();
+    if x.len() != n {
+        return Err(IntegrateError::LengthMismatch { y: n, x: x.len() });
+    }
+    if n < 2 {
+        return Err(IntegrateError::TooFewPoints);
+    }
+
+    let grid = classify_iter(x.iter().map(|&v| fx(v)), A::TOLERANCE)?;
+    let integral = if let Some(h0) = grid.step {
+        let interior_sum = pairwise_fold(1, n - 1, &|acc, i| acc + fy(y[i]));
+        h0 * fmla(fy(y[0]) + fy(y[n - 1]), A::HALF, interior_sum)
+    } else {
+        pairwise_fold(0, n - 1, &|acc, i| {
+            let dx = fx(x[i + 1]) - fx(x[i]);
+            fmla(dx * A::HALF, fy(y[i]) + fy(y[i + 1]), acc)
+        })
+    };
+    if grid.descending {
+        Ok(-integral)
+    } else {
+        Ok(integral)
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::trapezoid::classify;
+use crate::{boole_even_f64, simpson_even_f64, simpson_f64, trapezoid_even_f64, trapezoid_f64};
+
+/// Integration rule over sampled data, selectable at runtime through `dyn Quadrature`.
+///
+/// ```
+/// use trapez_integrate::{Boole, Quadrature, Simpson, Trapezoid};
+///
+/// let rules: [&dyn Quadrature; 3] = [&Trapezoid, &Simpson, &Boole];
+/// let x = [0., 0.25, 0.5, 0.75, 1.];
+/// let y = x.map(|x| 2. * x);
+/// for rule in rules {
+///     assert!((rule.integrate(&y, &x) - 1.).abs() < 1e-12);
+/// }
+/// ```
+pub trait Quadrature {
+    /// Integrates `y` over the abscissas `x`, NaN if the rule cannot handle the input.
+    fn integrate(&self, y: &[f64], x: &[f64]) -> f64;
+}
+
+/// The trapezoidal rule, see [trapezoid_f64].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Trapezoid;
+
+/// Composite Simpson's rule for arbitrary spacing, see [simpson_f64].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Simpson;
+
+/// Composite Boole's rule, see [boole_even_f64].
+///
+/// `x` must be uniformly spaced in either direction with `4k + 1` samples,
+/// anything else gives NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Boole;
+
+impl Quadrature for Trapezoid {
+    fn integrate(&self, y: &[f64], x: &[f64]) -> f64 {
+        trapezoid_f64(y, x)
+    }
+}
+
+impl Quadrature for Simpson {
+    fn integrate(&self, y: &[f64], x: &[f64]) -> f64 {
+        simpson_f64(y, x)
+    }
+}
+
+impl Quadrature for Boole {
+    fn integrate(&self, y: &[f64], x: &[f64]) -> f64 {
+        if y.len() != x.len() {
+            return f64::NAN;
+        }
+        // the panel weights are symmetric, so descending input needs only |dx|
+        match classify(x) {
+            Ok(grid) => grid.step.map_or(f64::NAN, |h| boole_even_f64(y, h.abs())),
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
+/// Integration method selected by value, see [integrate_f64].
+///
+/// The discriminants are fixed, so bindings can pass the method as a plain integer.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Method {
+    /// The trapezoidal rule, see [trapezoid_f64].
+    #[default]
+    Trapezoid = 0,
+    /// Composite Simpson's rule for arbitrary spacing, see [simpson_f64].
+    Simpson = 1,
+    /// Composite Boole's rule, see [Boole].
+    Boole = 2,
+    /// Natural cubic spline, see `spline_integrate_f64`, NaN without the `alloc` feature.
+    SplineCubic = 3,
+}
+
+/// Integrates `y` over `x` with the rule selected by `method`.
+///
+/// A single entry point for language bindings, every rule keeps its own input
+/// requirements and returns NaN for data it cannot handle.
+pub fn integrate_f64(method: Method, y: &[f64], x: &[f64]) -> f64 {
+    match method {
+        Method::Trapezoid => Trapezoid.integrate(y, x),
+        Method::Simpson => Simpson.integrate(y, x),
+        Method::Boole => Boole.integrate(y, x),
+        #[cfg(feature = "alloc")]
+        Method::SplineCubic => crate::spline_integrate_f64(y, x),
+        #[cfg(not(feature = "alloc"))]
+        Method::SplineCubic => f64::NAN,
+    }
+}
+
+/// Integrates evenly spaced samples with the highest order Newton–Cotes rule the
+/// sample count allows.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// With `n = y.len()` the rule is chosen as follows:
+/// * `n = 4k + 1`, `k >= 1` (5, 9, 13, ...): [boole_even_f64], exact up to degree five.
+/// * any other `n >= 3`: [simpson_even_f64], exact up to degree three. An even `n`
+///   leaves an odd number of intervals, the last three then use Simpson's 3/8 rule.
+/// * `n = 2`: [trapezoid_even_f64], exact for lines.
+///
+/// Returns NaN if `y` has fewer than 2 samples or `dx <= 0`.
+pub fn integrate_best_even_f64(y: &[f64], dx: f64) -> f64 {
+    let n = y.len();
+    if n >= 5 && (n - 1).is_multiple_of(4) {
+        boole_even_f64(y, dx)
+    } else if n >= 3 {
+        simpson_even_f64(y, dx)
+    } else {
+        trapezoid_even_f64(y, dx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quadrature_dispatch() {
+        let x: Vec<f64> = (0..17)
+            .map(|i| i as f64 * core::f64::consts::PI / 16.)
+            .collect();
+        let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+        let rules: Vec<(Box<dyn Quadrature>, f64)> = vec![
+            (Box::new(Trapezoid), 1e-2),
+            (Box::new(Simpson), 1e-4),
+            (Box::new(Boole), 1e-6),
+        ];
+        for (rule, tol) in rules {
+            let err = (rule.integrate(&y, &x) - 2.).abs();
+            assert!(err < tol, "{err} over {tol}");
+        }
+        // descending abscissas give the same positive integral for every rule
+        let rev: Vec<f64> = x.iter().rev().copied().collect();
+        let rules: [&dyn Quadrature; 3] = [&Trapezoid, &Simpson, &Boole];
+        for rule in rules {
+            let forward = rule.integrate(&y, &x);
+            assert!((rule.integrate(&y, &rev) - forward).abs() < 1e-12);
+        }
+        assert!(Boole.integrate(&y[1..], &x[1..]).is_nan());
+    }
+
+    #[test]
+    fn test_integrate_method() {
+        let x: Vec<f64> = (0..17)
+            .map(|i| i as f64 * core::f64::consts::PI / 16.)
+            .collect();
+        let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+        for method in [
+            Method::Trapezoid,
+            Method::Simpson,
+            Method::Boole,
+            Method::SplineCubic,
+        ] {
+            let err = (integrate_f64(method, &y, &x) - 2.).abs();
+            if cfg!(feature = "alloc") || method != Method::SplineCubic {
+                assert!(err < 1e-2, "{method:?}: {err}");
+            } else {
+                assert!(err.is_nan());
+            }
+        }
+        // descending uniform and irregular grids give the positive integral too
+        let rev: Vec<f64> = x.iter().rev().copied().collect();
+        let irregular: Vec<f64> = rev.iter().map(|x| x * x / core::f64::consts::PI).collect();
+        for grid in [rev, irregular] {
+            let y: Vec<f64> = grid.iter().map(|x| x.sin()).collect();
+            for method in [Method::Trapezoid, Method::Simpson, Method::SplineCubic] {
+                if cfg!(feature = "alloc") || method != Method::SplineCubic {
+                    let result = integrate_f64(method, &y, &grid);
+                    assert!((result - 2.).abs() < 2e-2, "{method:?}: {result}");
+                }
+            }
+        }
+        assert_eq!(integrate_f64(Method::Simpson, &y, &x), simpson_f64(&y, &x));
+        assert_eq!(Method::SplineCubic as u32, 3);
+        assert!(integrate_f64(Method::Boole, &y[1..], &x[1..]).is_nan());
+    }
+
+    #[test]
+    fn test_integrate_best_even() {
+        let dx = 0.125;
+        let samples = |n: usize| -> Vec<f64> { (0..n).map(|i| (i as f64 * dx).exp()).collect() };
+        for n in [5, 9, 13] {
+            let y = samples(n);
+            assert_eq!(integrate_best_even_f64(&y, dx), boole_even_f64(&y, dx));
+        }
+        for n in [3, 6, 7, 8] {
+            let y = samples(n);
+            assert_eq!(integrate_best_even_f64(&y, dx), simpson_even_f64(&y, dx));
+        }
+        assert_eq!(integrate_best_even_f64(&[1., 3.], dx), 0.25);
+        assert!(integrate_best_even_f64(&[1.], dx).is_nan());
+        assert!(integrate_best_even_f64(&samples(9), 0.).is_nan());
+    }
+}
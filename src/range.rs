@@ -0,0 +1,245 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::trapezoid::trapezoid;
+
+/// Integrates the piecewise linear interpolant of the samples over `[a, b]`.
+///
+/// The function values at `a` and `b` are linearly interpolated from the
+/// neighbouring samples, the two partial end segments are added to the
+/// trapezoid integral of the samples lying inside `[a, b]`.
+/// If `a > b` the result is the negated integral over `[b, a]`.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of ascending abscissas.
+/// * `a` - Lower bound, must lie in `[x[0], x[n - 1]]`.
+/// * `b` - Upper bound, must lie in `[x[0], x[n - 1]]`.
+///
+/// # Returns
+/// The approximate integral over `[a, b]`, or NaN if the input is invalid
+/// or a bound is outside the sampled range.
+pub fn trapezoid_range_f64(y: &[f64], x: &[f64], a: f64, b: f64) -> f64 {
+    trapezoid_range(y, x, a, b)
+}
+
+/// Integrates the piecewise linear interpolant of the samples over `[a, b]`.
+///
+/// The function values at `a` and `b` are linearly interpolated from the
+/// neighbouring samples, the two partial end segments are added to the
+/// trapezoid integral of the samples lying inside `[a, b]`.
+/// If `a > b` the result is the negated integral over `[b, a]`.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of ascending abscissas.
+/// * `a` - Lower bound, must lie in `[x[0], x[n - 1]]`.
+/// * `b` - Upper bound, must lie in `[x[0], x[n - 1]]`.
+///
+/// # Returns
+/// The approximate integral over `[a, b]`, or NaN if the input is invalid
+/// or a bound is outside the sampled range.
+pub fn trapezoid_range_f32(y: &[f32], x: &[f32], a: f32, b: f32) -> f32 {
+    trapezoid_range(y, x, a, b)
+}
+
+pub(crate) fn trapezoid_range<T: TrapezSample>(y: &[T], x: &[T], a: T, b: T) -> T {
+    let n = y.len();
+    if n < 2 || x.len() != n {
+        return T::nan();
+    }
+    // comparisons against NaN are false, so NaN bounds are rejected as well
+    let inside = |t: T| t >= x[0] && t <= x[n - 1];
+    if !inside(a) || !inside(b) {
+        return T::nan();
+    }
+    if a > b {
+        return -trapezoid_range(y, x, b, a);
+    }
+    if a == b {
+        return T::zero();
+    }
+
+    // segment holding `a`: last i with x[i] <= a
+    let ia = (x.partition_point(|&v| v <= a) - 1).min(n - 2);
+    // segment holding `b`: last i with x[i] < b
+    let ib = x.partition_point(|&v| v < b).clamp(1, n - 1) - 1;
+
+    let ya = interpolate(y, x, ia, a);
+    let yb = interpolate(y, x, ib, b);
+    if ia == ib {
+        return (b - a) * T::HALF * (ya + yb);
+    }
+
+    let head = (x[ia + 1] - a) * T::HALF * (ya + y[ia + 1]);
+    let tail = (b - x[ib]) * T::HALF * (y[ib] + yb);
+    let interior = if ib > ia + 1 {
+        trapezoid(&y[ia + 1..=ib], &x[ia + 1..=ib])
+    } else {
+        T::zero()
+    };
+    head + interior + tail
+}
+
+/// Integrates the samples over `[a, b]`, which may extend beyond the sampled range.
+///
+/// Outside `[x[0], x[n - 1]]` the function is **assumed constant** at its endpoint
+/// value: `[a, x[0]]` contributes `y[0] * (x[0] - a)` and `[x[n - 1], b]` contributes
+/// `y[n - 1] * (b - x[n - 1])`. Within the samples this is [trapezoid_range_f64].
+/// If `a > b` the result is the negated integral over `[b, a]`.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of ascending abscissas.
+/// * `a` - Lower bound.
+/// * `b` - Upper bound.
+///
+/// # Returns
+/// The approximate integral over `[a, b]`, or NaN if the input is invalid
+/// or a bound is NaN.
+pub fn trapezoid_extrapolated_f64(y: &[f64], x: &[f64], a: f64, b: f64) -> f64 {
+    trapezoid_extrapolated(y, x, a, b)
+}
+
+/// Integrates the samples over `[a, b]`, which may extend beyond the sampled range.
+///
+/// Outside `[x[0], x[n - 1]]` the function is **assumed constant** at its endpoint
+/// value: `[a, x[0]]` contributes `y[0] * (x[0] - a)` and `[x[n - 1], b]` contributes
+/// `y[n - 1] * (b - x[n - 1])`. Within the samples this is [trapezoid_range_f32].
+/// If `a > b` the result is the negated integral over `[b, a]`.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of ascending abscissas.
+/// * `a` - Lower bound.
+/// * `b` - Upper bound.
+///
+/// # Returns
+/// The approximate integral over `[a, b]`, or NaN if the input is invalid
+/// or a bound is NaN.
+pub fn trapezoid_extrapolated_f32(y: &[f32], x: &[f32], a: f32, b: f32) -> f32 {
+    trapezoid_extrapolated(y, x, a, b)
+}
+
+fn trapezoid_extrapolated<T: TrapezSample>(y: &[T], x: &[T], a: T, b: T) -> T {
+    let n = y.len();
+    if n < 2 || x.len() != n || a.is_nan() || b.is_nan() {
+        return T::nan();
+    }
+    if a > b {
+        return -trapezoid_extrapolated(y, x, b, a);
+    }
+    let (first, last) = (x[0], x[n - 1]);
+    if b <= first {
+        return y[0] * (b - a);
+    }
+    if a >= last {
+        return y[n - 1] * (b - a);
+    }
+    let below = if a < first {
+        y[0] * (first - a)
+    } else {
+        T::zero()
+    };
+    let above = if b > last {
+        y[n - 1] * (b - last)
+    } else {
+        T::zero()
+    };
+    below + trapezoid_range(y, x, a.max(first), b.min(last)) + above
+}
+
+/// Linear interpolation of the samples at `t` within segment `i`.
+#[inline]
+pub(crate) fn interpolate<T: TrapezSample>(y: &[T], x: &[T], i: usize, t: T) -> T {
+    let dx = x[i + 1] - x[i];
+    if dx == T::zero() {
+        return y[i];
+    }
+    fmla(t - x[i], (y[i + 1] - y[i]) / dx, y[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_range_sample_points() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        assert_eq!(trapezoid_range_f64(&y, &x, 1., 9.), trapezoid_f64(&y, &x));
+        assert_eq!(
+            trapezoid_range_f64(&y, &x, 2., 7.),
+            trapezoid_f64(&y[1..5], &x[1..5])
+        );
+        assert_eq!(trapezoid_range_f64(&y, &x, 4., 6.), 5.);
+        assert_eq!(trapezoid_range_f64(&y, &x, 9., 1.), -30.5);
+        assert_eq!(trapezoid_range_f64(&y, &x, 4., 4.), 0.);
+    }
+
+    #[test]
+    fn test_trapezoid_range_interpolated() {
+        // y = 2x + 1 is integrated exactly by the trapezoid rule
+        let x = [0., 1., 2.5, 4., 6., 10.];
+        let y = x.map(|x: f64| 2. * x + 1.);
+        let anti = |t: f64| t * t + t;
+        assert!((trapezoid_range_f64(&y, &x, 2.5, 7.3) - (anti(7.3) - anti(2.5))).abs() < 1e-12);
+        assert!((trapezoid_range_f64(&y, &x, 1.2, 2.1) - (anti(2.1) - anti(1.2))).abs() < 1e-12);
+        assert!((trapezoid_range_f64(&y, &x, 0.5, 1.5) - (anti(1.5) - anti(0.5))).abs() < 1e-12);
+        assert!(trapezoid_range_f64(&y, &x, -1., 2.).is_nan());
+        assert!(trapezoid_range_f64(&y, &x, 1., 10.5).is_nan());
+        assert!(trapezoid_range_f32(&[1., 2.], &[0., 1.], 0., f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_extrapolated() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let inside = trapezoid_f64(&y, &x);
+        assert_eq!(trapezoid_extrapolated_f64(&y, &x, 1., 9.), inside);
+        assert_eq!(
+            trapezoid_extrapolated_f64(&y, &x, -1., 12.),
+            10. + inside + 6.
+        );
+        assert_eq!(
+            trapezoid_extrapolated_f64(&y, &x, 12., -1.),
+            -(10. + inside + 6.)
+        );
+        assert_eq!(
+            trapezoid_extrapolated_f64(&y, &x, 0., 4.),
+            5. + trapezoid_range_f64(&y, &x, 1., 4.)
+        );
+        // both bounds on the same side of the samples
+        assert_eq!(trapezoid_extrapolated_f64(&y, &x, -3., 0.5), 17.5);
+        assert_eq!(trapezoid_extrapolated_f64(&y, &x, 10., 11.), 2.);
+        assert!(trapezoid_extrapolated_f32(&[1., 2.], &[0., 1.], f32::NAN, 1.).is_nan());
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{classify, validate};
+
+/// Left Riemann sum, every interval contributes `y[i] * (x[i + 1] - x[i])`.
+///
+/// `x` follows the same rules as in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The approximate integral, or NaN if the input is invalid (see [try_trapezoid_f64](crate::try_trapezoid_f64)).
+pub fn riemann_left_f64(y: &[f64], x: &[f64]) -> f64 {
+    riemann(y, x, 0)
+}
+
+/// Left Riemann sum, every interval contributes `y[i] * (x[i + 1] - x[i])`.
+///
+/// `x` follows the same rules as in [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The approximate integral, or NaN if the input is invalid (see [try_trapezoid_f32](crate::try_trapezoid_f32)).
+pub fn riemann_left_f32(y: &[f32], x: &[f32]) -> f32 {
+    riemann(y, x, 0)
+}
+
+/// Right Riemann sum, every interval contributes `y[i + 1] * (x[i + 1] - x[i])`.
+///
+/// `x` follows the same rules as in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The approximate integral, or NaN if the input is invalid (see [try_trapezoid_f64](crate::try_trapezoid_f64)).
+pub fn riemann_right_f64(y: &[f64], x: &[f64]) -> f64 {
+    riemann(y, x, 1)
+}
+
+/// Right Riemann sum, every interval contributes `y[i + 1] * (x[i + 1] - x[i])`.
+///
+/// `x` follows the same rules as in [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The approximate integral, or NaN if the input is invalid (see [try_trapezoid_f32](crate::try_trapezoid_f32)).
+pub fn riemann_right_f32(y: &[f32], x: &[f32]) -> f32 {
+    riemann(y, x, 1)
+}
+
+/// Riemann sum sampling every interval at its left (`offset = 0`) or right (`offset = 1`) edge.
+fn riemann<T: TrapezSample>(y: &[T], x: &[T], offset: usize) -> T {
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    let integral = pairwise_fold(0, y.len() - 1, &|acc: T, i| {
+        acc + y[i + offset] * (x[i + 1] - x[i])
+    });
+    if grid.descending { -integral } else { integral }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_riemann_bracket_trapezoid() {
+        let x: [f64; 9] = [0., 0.1, 0.3, 0.35, 0.6, 0.7, 0.8, 1.2, 1.5];
+        let y = x.map(f64::exp);
+        let left = riemann_left_f64(&y, &x);
+        let right = riemann_right_f64(&y, &x);
+        let trap = trapezoid_f64(&y, &x);
+        assert!(left < trap && trap < right, "{left} {trap} {right}");
+        assert!((trap - 0.5 * (left + right)).abs() < 1e-12);
+        assert_eq!(riemann_left_f32(&[1., 2., 3.], &[0., 1., 3.]), 5.);
+        assert_eq!(riemann_right_f32(&[1., 2., 3.], &[0., 1., 3.]), 8.);
+        assert!(riemann_left_f64(&y, &x[1..]).is_nan());
+    }
+}
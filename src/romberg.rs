@@ -0,0 +1,117 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use num_traits::AsPrimitive;
+
+/// Romberg integration of evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// The trapezoidal rule is evaluated on the full grid and on every grid obtained
+/// by repeatedly dropping every other sample, and the estimates are combined with
+/// Richardson extrapolation.
+///
+/// Returns NaN unless `y` has `2^k + 1` samples and `dx > 0`.
+pub fn romberg_even_f64(y: &[f64], dx: f64) -> f64 {
+    romberg_even(y, dx)
+}
+
+/// Romberg integration of evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// The trapezoidal rule is evaluated on the full grid and on every grid obtained
+/// by repeatedly dropping every other sample, and the estimates are combined with
+/// Richardson extrapolation.
+///
+/// Returns NaN unless `y` has `2^k + 1` samples and `dx > 0`.
+pub fn romberg_even_f32(y: &[f32], dx: f32) -> f32 {
+    romberg_even(y, dx)
+}
+
+fn romberg_even<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 2 || !(n - 1).is_power_of_two() || dx <= T::zero() {
+        return T::nan();
+    }
+    let intervals = n - 1;
+    let levels = intervals.trailing_zeros() as usize;
+
+    let mut prev = [T::zero(); usize::BITS as usize];
+    let mut cur = [T::zero(); usize::BITS as usize];
+
+    // coarsest trapezoid uses only the endpoints
+    let mut h = dx * (intervals as f64).as_();
+    prev[0] = h * T::HALF * (y[0] + y[n - 1]);
+
+    for level in 1..=levels {
+        // the trapezoid at half the step reuses the previous estimate
+        // and adds only the newly introduced midpoints
+        let stride = intervals >> level;
+        h = h * T::HALF;
+        let mut mid = T::zero();
+        for &v in y[stride..].iter().step_by(2 * stride) {
+            mid += v;
+        }
+        cur[0] = fmla(T::HALF, prev[0], h * mid);
+
+        let mut factor: T = 1f64.as_();
+        for j in 1..=level {
+            factor = factor * 4f64.as_();
+            cur[j] = cur[j - 1] + (cur[j - 1] - prev[j - 1]) / (factor - T::one());
+        }
+        core::mem::swap(&mut prev, &mut cur);
+    }
+    prev[levels]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_romberg_sin() {
+        let n = 65;
+        let dx = std::f64::consts::PI / (n - 1) as f64;
+        let y: Vec<f64> = (0..n).map(|i| (i as f64 * dx).sin()).collect();
+        let result = romberg_even_f64(&y, dx);
+        assert!((result - 2.0).abs() < 1e-14, "{result}");
+    }
+
+    #[test]
+    fn test_romberg_invalid() {
+        assert!(romberg_even_f64(&[1., 2., 3., 4.], 0.1).is_nan());
+        assert!(romberg_even_f64(&[1., 2., 3.], 0.).is_nan());
+        assert_eq!(romberg_even_f32(&[1., 3.], 0.5), 1.);
+    }
+}
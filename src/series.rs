@@ -0,0 +1,108 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::try_trapezoid_f64;
+use polars::prelude::*;
+
+/// How [trapezoid_series] treats rows holding a null value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullPolicy {
+    /// Rows where either `y` or `x` is null are dropped before integrating,
+    /// the neighbouring valid rows are joined by a single segment.
+    #[default]
+    Skip,
+    /// Any null value fails the integration.
+    Error,
+}
+
+/// Integrates the `f64` column `y` over the `f64` column `x` with the trapezoidal rule.
+///
+/// Both series are downcast to [Float64Chunked], other data types are rejected rather
+/// than cast. Contiguous columns without nulls are integrated in place, otherwise the
+/// valid rows are gathered first according to `nulls`. The remaining samples follow
+/// the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Errors
+/// * [PolarsError::SchemaMismatch] if either series is not `Float64`.
+/// * [PolarsError::ShapeMismatch] if the lengths differ.
+/// * [PolarsError::ComputeError] for a null under [NullPolicy::Error], or if the
+///   samples cannot be integrated, see [try_trapezoid_f64].
+pub fn trapezoid_series(y: &Series, x: &Series, nulls: NullPolicy) -> PolarsResult<f64> {
+    let (y, x) = (y.f64()?, x.f64()?);
+    polars_ensure!(
+        y.len() == x.len(),
+        ShapeMismatch: "y has {} rows but x has {}", y.len(), x.len()
+    );
+    let integrate = |y: &[f64], x: &[f64]| {
+        try_trapezoid_f64(y, x).map_err(|e| polars_err!(ComputeError: "{e}"))
+    };
+    if let (Ok(ys), Ok(xs)) = (y.cont_slice(), x.cont_slice()) {
+        return integrate(ys, xs);
+    }
+    let mut ys = Vec::with_capacity(y.len());
+    let mut xs = Vec::with_capacity(x.len());
+    for (i, pair) in y.iter().zip(x.iter()).enumerate() {
+        match pair {
+            (Some(yi), Some(xi)) => {
+                ys.push(yi);
+                xs.push(xi);
+            }
+            _ if nulls == NullPolicy::Error => {
+                polars_bail!(ComputeError: "null value at row {i}")
+            }
+            _ => {}
+        }
+    }
+    integrate(&ys, &xs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_series() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let result = trapezoid_series(
+            &Series::new("y".into(), &y),
+            &Series::new("x".into(), &x),
+            NullPolicy::Error,
+        );
+        assert_eq!(result.unwrap(), trapezoid_f64(&y, &x));
+
+        let with_null = Series::new("y".into(), &[Some(5.), Some(6.), None, Some(4.)]);
+        let x = Series::new("x".into(), &[1., 2., 4., 6.]);
+        let skipped = trapezoid_series(&with_null, &x, NullPolicy::Skip).unwrap();
+        assert_eq!(skipped, trapezoid_f64(&[5., 6., 4.], &[1., 2., 6.]));
+        assert!(trapezoid_series(&with_null, &x, NullPolicy::Error).is_err());
+        let ints = Series::new("x".into(), &[1i64, 2, 4, 6]);
+        assert!(trapezoid_series(&with_null, &ints, NullPolicy::Skip).is_err());
+    }
+}
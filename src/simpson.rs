@@ -0,0 +1,310 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::trapezoid::classify;
+use num_traits::AsPrimitive;
+
+/// Composite Simpson's rule for arbitrarily spaced samples.
+///
+/// Each pair of consecutive intervals is integrated by the parabola through its
+/// three samples. When the number of intervals is odd, the last interval is
+/// integrated with the parabola through the last three samples. Uniformly spaced
+/// input is dispatched to [simpson_even_f64].
+///
+/// `x` must be monotonic. Descending abscissas are integrated as if the samples
+/// were reversed into ascending order, like in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of abscissas.
+///
+/// # Returns
+/// The approximate definite integral, or NaN if fewer than 3 samples are given,
+/// the lengths of `y` and `x` differ, `x` is not monotonic or repeats an abscissa.
+pub fn simpson_f64(y: &[f64], x: &[f64]) -> f64 {
+    simpson(y, x)
+}
+
+/// Composite Simpson's rule for arbitrarily spaced samples.
+///
+/// Each pair of consecutive intervals is integrated by the parabola through its
+/// three samples. When the number of intervals is odd, the last interval is
+/// integrated with the parabola through the last three samples. Uniformly spaced
+/// input is dispatched to [simpson_even_f32].
+///
+/// `x` must be monotonic. Descending abscissas are integrated as if the samples
+/// were reversed into ascending order, like in [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of abscissas.
+///
+/// # Returns
+/// The approximate definite integral, or NaN if fewer than 3 samples are given,
+/// the lengths of `y` and `x` differ, `x` is not monotonic or repeats an abscissa.
+pub fn simpson_f32(y: &[f32], x: &[f32]) -> f32 {
+    simpson(y, x)
+}
+
+/// Composite Simpson's 1/3 rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// When the number of intervals is odd (even number of samples) the last three
+/// intervals are integrated with Simpson's 3/8 rule, so the result stays exact
+/// for cubics regardless of the sample count.
+///
+/// Returns NaN if `y` has fewer than 3 samples or `dx <= 0`.
+pub fn simpson_even_f64(y: &[f64], dx: f64) -> f64 {
+    simpson_even(y, dx)
+}
+
+/// Composite Simpson's 1/3 rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// When the number of intervals is odd (even number of samples) the last three
+/// intervals are integrated with Simpson's 3/8 rule, so the result stays exact
+/// for cubics regardless of the sample count.
+///
+/// Returns NaN if `y` has fewer than 3 samples or `dx <= 0`.
+pub fn simpson_even_f32(y: &[f32], dx: f32) -> f32 {
+    simpson_even(y, dx)
+}
+
+/// Composite Simpson's 3/8 rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Requires exactly `3k + 1` samples with `k >= 1`, the interior weights follow
+/// the pattern `1, 3, 3, 2, 3, 3, 2, ..., 3, 3, 1`. Exact for cubics.
+///
+/// Returns NaN if the sample count is not of the form `3k + 1` or `dx <= 0`,
+/// use [simpson_even_f64] for arbitrary counts.
+pub fn simpson38_even_f64(y: &[f64], dx: f64) -> f64 {
+    simpson38_even(y, dx)
+}
+
+/// Composite Simpson's 3/8 rule for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Requires exactly `3k + 1` samples with `k >= 1`, the interior weights follow
+/// the pattern `1, 3, 3, 2, 3, 3, 2, ..., 3, 3, 1`. Exact for cubics.
+///
+/// Returns NaN if the sample count is not of the form `3k + 1` or `dx <= 0`,
+/// use [simpson_even_f32] for arbitrary counts.
+pub fn simpson38_even_f32(y: &[f32], dx: f32) -> f32 {
+    simpson38_even(y, dx)
+}
+
+pub(crate) fn simpson<T: TrapezSample>(y: &[T], x: &[T]) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 3 || x.len() != n {
+        return T::nan();
+    }
+
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    if let Some(h) = grid.step {
+        return simpson_even(y, h.abs());
+    }
+    // the parabola weights divide by every interval width
+    if x.windows(2).any(|w| w[0] == w[1]) {
+        return T::nan();
+    }
+
+    let six: T = 6f64.as_();
+    let two: T = 2f64.as_();
+    let mut integral = T::zero();
+    let mut i = 0;
+    while i + 2 < n {
+        let h0 = x[i + 1] - x[i];
+        let h1 = x[i + 2] - x[i + 1];
+        let hs = h0 + h1;
+        let w0 = two - h1 / h0;
+        let w1 = hs * hs / (h0 * h1);
+        let w2 = two - h0 / h1;
+        let panel = fmla(w0, y[i], fmla(w1, y[i + 1], w2 * y[i + 2]));
+        integral = fmla(hs / six, panel, integral);
+        i += 2;
+    }
+
+    if n.is_multiple_of(2) {
+        // Last interval taken from the parabola through the last three samples.
+        let h0 = x[n - 2] - x[n - 3];
+        let h1 = x[n - 1] - x[n - 2];
+        let hs = h0 + h1;
+        let alpha = fmla(two * h1, h1, 3f64.as_() * h0 * h1) / (six * hs);
+        let beta = fmla(h1, h1, 3f64.as_() * h0 * h1) / (six * h0);
+        let eta = h1 * h1 * h1 / (six * h0 * hs);
+        integral += fmla(alpha, y[n - 1], fmla(beta, y[n - 2], -eta * y[n - 3]));
+    }
+    if grid.descending { -integral } else { integral }
+}
+
+pub(crate) fn simpson_even<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 3 || dx <= T::zero() {
+        return T::nan();
+    }
+
+    if n % 2 == 1 {
+        return simpson13(y, dx);
+    }
+
+    // Odd interval count: 1/3 rule on the leading part, 3/8 rule on the last 3 intervals.
+    let s38 = simpson38(&y[n - 4..], dx);
+    if n == 4 {
+        s38
+    } else {
+        simpson13(&y[..n - 3], dx) + s38
+    }
+}
+
+/// Simpson's 1/3 rule over an odd number (>= 3) of evenly spaced samples.
+fn simpson13<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    let mut odd = T::zero();
+    let mut even = T::zero();
+    for pair in y[1..n - 1].chunks_exact(2) {
+        odd += pair[0];
+        even += pair[1];
+    }
+    // the interior has odd length, so its last sample at y[n - 2] is left over
+    odd += y[n - 2];
+    let inner = fmla(4f64.as_(), odd, fmla(2f64.as_(), even, y[0] + y[n - 1]));
+    dx / 3f64.as_() * inner
+}
+
+pub(crate) fn simpson38_even<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    if n < 4 || !(n - 1).is_multiple_of(3) || dx <= T::zero() {
+        return T::nan();
+    }
+    simpson38(y, dx)
+}
+
+/// Simpson's 3/8 rule over `3k + 1` (k >= 1) evenly spaced samples.
+pub(crate) fn simpson38<T: TrapezSample>(y: &[T], dx: T) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    let mut triple = T::zero();
+    let mut shared = T::zero();
+    for (i, &v) in y[1..n - 1].iter().enumerate() {
+        // every third interior sample joins two panels
+        if i % 3 == 2 {
+            shared += v;
+        } else {
+            triple += v;
+        }
+    }
+    let inner = fmla(
+        3f64.as_(),
+        triple,
+        fmla(2f64.as_(), shared, y[0] + y[n - 1]),
+    );
+    dx * 0.375f64.as_() * inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simpson_even_quadratic() {
+        // odd number of samples, x in [0, 2]
+        let y: Vec<f64> = (0..5).map(|i| (i as f64 * 0.5).powi(2)).collect();
+        assert!((simpson_even_f64(&y, 0.5) - 8. / 3.).abs() < 1e-12);
+        // even number of samples, x in [0, 2.5]
+        let y: Vec<f64> = (0..6).map(|i| (i as f64 * 0.5).powi(2)).collect();
+        assert!((simpson_even_f64(&y, 0.5) - 2.5f64.powi(3) / 3.).abs() < 1e-12);
+        // 3/8 rule only, x in [0, 1.5]
+        let y: Vec<f32> = (0..4).map(|i| (i as f32 * 0.5).powi(2)).collect();
+        assert!((simpson_even_f32(&y, 0.5) - 1.125).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_simpson_parabola() {
+        let f = |x: f64| 3. * x * x - x + 1.;
+        let anti = |x: f64| x * x * x - 0.5 * x * x + x;
+        let x = [0., 0.3, 1.];
+        let y = x.map(f);
+        assert!((simpson_f64(&y, &x) - anti(1.)).abs() < 1e-12);
+        // even number of samples exercises the last-interval correction
+        let x = [0., 0.3, 1., 1.6];
+        let y = x.map(f);
+        assert!((simpson_f64(&y, &x) - anti(1.6)).abs() < 1e-12);
+        // uniform input goes through the even path
+        let x = [0., 0.5, 1., 1.5, 2.];
+        let y = x.map(f);
+        assert_eq!(simpson_f64(&y, &x), simpson_even_f64(&y, 0.5));
+        // a repeated abscissa or a change of direction has no parabola
+        assert!(simpson_f64(&[1., 2., 3., 4.], &[0., 0.3, 0.3, 1.]).is_nan());
+        assert!(simpson_f32(&[1., 2., 3.], &[0., 0.5, 0.2]).is_nan());
+    }
+
+    #[test]
+    fn test_simpson38_even_cubic() {
+        let f = |x: f64| 2. * x * x * x - x * x + 0.5;
+        let anti = |x: f64| 0.5 * x.powi(4) - x * x * x / 3. + 0.5 * x;
+        for n in [4, 7, 10] {
+            let y: Vec<f64> = (0..n).map(|i| f(i as f64 * 0.25)).collect();
+            let b = (n - 1) as f64 * 0.25;
+            assert!(
+                (simpson38_even_f64(&y, 0.25) - anti(b)).abs() < 1e-12,
+                "{n}"
+            );
+        }
+        assert!(simpson38_even_f64(&[1.; 5], 0.25).is_nan());
+        assert!(simpson38_even_f32(&[1.; 4], -0.25).is_nan());
+    }
+
+    #[test]
+    fn test_simpson_even_invalid() {
+        assert!(simpson_even_f64(&[1., 2.], 0.1).is_nan());
+        assert!(simpson_even_f64(&[1., 2., 3.], 0.).is_nan());
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::trapezoid::trapezoid;
+
+/// Sorts the `(x, y)` pairs by `x` in place and integrates them with [trapezoid_f64](crate::trapezoid_f64).
+///
+/// Both slices are permuted together, so every `y[i]` stays with its `x[i]`.
+/// Pairs with equal abscissas are ordered by `y`, which makes the result independent
+/// of the order the pairs arrived in. The sort is an in-place heapsort, nothing is allocated.
+///
+/// # Returns
+/// The integral, or NaN if the lengths differ, fewer than two samples are given or
+/// an abscissa is NaN. The slices are left untouched unless the sort succeeds.
+pub fn sort_and_trapezoid_f64(y: &mut [f64], x: &mut [f64]) -> f64 {
+    sort_and_trapezoid(y, x)
+}
+
+/// Sorts the `(x, y)` pairs by `x` in place and integrates them with [trapezoid_f32](crate::trapezoid_f32).
+///
+/// Both slices are permuted together, so every `y[i]` stays with its `x[i]`.
+/// Pairs with equal abscissas are ordered by `y`, which makes the result independent
+/// of the order the pairs arrived in. The sort is an in-place heapsort, nothing is allocated.
+///
+/// # Returns
+/// The integral, or NaN if the lengths differ, fewer than two samples are given or
+/// an abscissa is NaN. The slices are left untouched unless the sort succeeds.
+pub fn sort_and_trapezoid_f32(y: &mut [f32], x: &mut [f32]) -> f32 {
+    sort_and_trapezoid(y, x)
+}
+
+fn sort_and_trapezoid<T: TrapezSample>(y: &mut [T], x: &mut [T]) -> T {
+    if y.len() != x.len() || y.len() < 2 || x.iter().any(|v| v.is_nan()) {
+        return T::nan();
+    }
+    co_sort(y, x);
+    trapezoid(y, x)
+}
+
+/// Heapsort of the pairs by `x`, then by `y`, `x` must not contain NaN.
+fn co_sort<T: TrapezSample>(y: &mut [T], x: &mut [T]) {
+    let n = x.len();
+    for root in (0..n / 2).rev() {
+        sift_down(y, x, root, n);
+    }
+    for end in (1..n).rev() {
+        y.swap(0, end);
+        x.swap(0, end);
+        sift_down(y, x, 0, end);
+    }
+}
+
+fn sift_down<T: TrapezSample>(y: &mut [T], x: &mut [T], mut root: usize, end: usize) {
+    let less = |y: &[T], x: &[T], i: usize, j: usize| x[i] < x[j] || (x[i] == x[j] && y[i] < y[j]);
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && less(y, x, child, child + 1) {
+            child += 1;
+        }
+        if !less(y, x, root, child) {
+            return;
+        }
+        y.swap(root, child);
+        x.swap(root, child);
+        root = child;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_and_trapezoid() {
+        // the pairs of `test_trapezoid` in scrambled order
+        let mut y = [6., 2., 5., 4., 1., 6.];
+        let mut x = [2., 9., 1., 6., 4., 7.];
+        assert_eq!(sort_and_trapezoid_f64(&mut y, &mut x), 30.5);
+        assert_eq!(x, [1., 2., 4., 6., 7., 9.]);
+        assert_eq!(y, [5., 6., 1., 4., 6., 2.]);
+
+        let mut y = [3., 1., 2.];
+        let mut x = [1., 1., 0.];
+        assert_eq!(sort_and_trapezoid_f32(&mut y, &mut x), 1.5);
+        assert_eq!(y, [2., 1., 3.]);
+
+        let mut y = [1., 2.];
+        let mut x = [f64::NAN, 0.];
+        assert!(sort_and_trapezoid_f64(&mut y, &mut x).is_nan());
+        assert_eq!(y, [1., 2.]);
+    }
+}
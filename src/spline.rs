@@ -0,0 +1,151 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{classify, validate};
+use alloc::{vec, vec::Vec};
+use num_traits::AsPrimitive;
+
+/// Integrates the natural cubic spline through the samples.
+///
+/// The second derivatives `M` of the spline are solved from the tridiagonal system
+/// with `M[0] = M[n - 1] = 0`, then every segment is integrated exactly as
+/// `h * (y[i] + y[i + 1]) / 2 - h^3 * (M[i] + M[i + 1]) / 24`. For smooth data this is
+/// far more accurate than the trapezoid rule on the same samples, the natural end
+/// conditions limit it near the ends unless the curvature vanishes there.
+/// Needs `O(n)` scratch memory. `x` follows the rules of [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Returns
+/// The integral, or NaN if the lengths differ, fewer than two samples are given,
+/// `x` is not monotonic or contains repeated abscissas.
+pub fn spline_integrate_f64(y: &[f64], x: &[f64]) -> f64 {
+    spline_integrate(y, x)
+}
+
+/// Integrates the natural cubic spline through the samples.
+///
+/// The second derivatives `M` of the spline are solved from the tridiagonal system
+/// with `M[0] = M[n - 1] = 0`, then every segment is integrated exactly as
+/// `h * (y[i] + y[i + 1]) / 2 - h^3 * (M[i] + M[i + 1]) / 24`. For smooth data this is
+/// far more accurate than the trapezoid rule on the same samples, the natural end
+/// conditions limit it near the ends unless the curvature vanishes there.
+/// Needs `O(n)` scratch memory. `x` follows the rules of [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Returns
+/// The integral, or NaN if the lengths differ, fewer than two samples are given,
+/// `x` is not monotonic or contains repeated abscissas.
+pub fn spline_integrate_f32(y: &[f32], x: &[f32]) -> f32 {
+    spline_integrate(y, x)
+}
+
+fn spline_integrate<T: TrapezSample>(y: &[T], x: &[T]) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    if x.windows(2).any(|w| w[1] == w[0]) {
+        return T::nan();
+    }
+    let m = natural_second_derivatives(y, x);
+    let twenty_fourth: T = (1. / 24.).as_();
+    let integral = pairwise_fold(0, y.len() - 1, &|acc: T, i| {
+        let h = x[i + 1] - x[i];
+        let linear = h * T::HALF * (y[i] + y[i + 1]);
+        let curvature = h * h * h * twenty_fourth * (m[i] + m[i + 1]);
+        acc + (linear - curvature)
+    });
+    if grid.descending { -integral } else { integral }
+}
+
+/// Second derivatives of the natural cubic spline by the Thomas algorithm,
+/// `x` must not repeat abscissas. The system is diagonally dominant, no pivoting is needed.
+fn natural_second_derivatives<T: TrapezSample>(y: &[T], x: &[T]) -> Vec<T>
+where
+    f64: AsPrimitive<T>,
+{
+    let n = y.len();
+    let mut m = vec![T::zero(); n];
+    if n < 3 {
+        return m;
+    }
+    let six: T = 6f64.as_();
+    let two: T = 2f64.as_();
+    let slope = |i: usize| (y[i + 1] - y[i]) / (x[i + 1] - x[i]);
+    // forward sweep, `upper[i]` is the eliminated super-diagonal of row `i + 1`
+    let mut upper = vec![T::zero(); n - 2];
+    for i in 1..n - 1 {
+        let h_lo = x[i] - x[i - 1];
+        let h_hi = x[i + 1] - x[i];
+        let rhs = six * (slope(i) - slope(i - 1));
+        let (prev_upper, prev_rhs) = if i > 1 {
+            (upper[i - 2], m[i - 1])
+        } else {
+            (T::zero(), T::zero())
+        };
+        let pivot = fmla(-h_lo, prev_upper, two * (h_lo + h_hi));
+        upper[i - 1] = h_hi / pivot;
+        m[i] = fmla(-h_lo, prev_rhs, rhs) / pivot;
+    }
+    // back substitution, m[n - 1] stays zero
+    for i in (1..n - 2).rev() {
+        m[i] = fmla(-upper[i - 1], m[i + 1], m[i]);
+    }
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_spline_integrate() {
+        // sin has zero curvature at both ends of [0, pi], matching the natural conditions
+        let x: Vec<f64> = (0..=16)
+            .map(|i| core::f64::consts::PI * (i as f64 / 16.).powf(1.5))
+            .collect();
+        let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+        let spline_err = (spline_integrate_f64(&y, &x) - 2.).abs();
+        let trapezoid_err = (trapezoid_f64(&y, &x) - 2.).abs();
+        assert!(
+            spline_err * 100. < trapezoid_err,
+            "{spline_err} vs {trapezoid_err}"
+        );
+
+        // a straight line has no curvature, the spline is the line itself
+        assert_eq!(spline_integrate_f32(&[1., 3., 5.], &[2., 1., 0.]), 6.);
+        assert!(spline_integrate_f64(&[1., 2., 3.], &[0., 1., 1.]).is_nan());
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::trapezoid::add_segment;
+
+/// Running trapezoidal integral over samples that arrive one at a time.
+///
+/// Each [push](StreamingTrapezoid::push) adds the trapezoid between the previous
+/// and the current sample, so no history has to be buffered.
+/// Abscissas are used as given, pushing a smaller `x` than the previous one
+/// subtracts the corresponding area.
+#[derive(Debug, Clone, Copy)]
+pub struct StreamingTrapezoid<T> {
+    last: Option<(T, T)>,
+    integral: T,
+    count: usize,
+}
+
+impl<T: TrapezSample> StreamingTrapezoid<T> {
+    /// Creates an empty integrator with a value of zero.
+    pub fn new() -> Self {
+        Self {
+            last: None,
+            integral: T::zero(),
+            count: 0,
+        }
+    }
+
+    /// Adds the sample `y` at abscissa `x`.
+    ///
+    /// The first sample only sets the starting point and contributes no area.
+    pub fn push(&mut self, x: T, y: T) {
+        if let Some((x0, y0)) = self.last {
+            self.integral = add_segment(self.integral, &[y0, y], &[x0, x]);
+        }
+        self.last = Some((x, y));
+        self.count += 1;
+    }
+
+    /// The integral from the first pushed abscissa to the last one.
+    pub fn value(&self) -> T {
+        self.integral
+    }
+
+    /// Number of samples pushed so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T: TrapezSample> Default for StreamingTrapezoid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Integrates `(x, y)` pairs produced lazily, keeping only the previous point.
+///
+/// Every segment contributes `0.5 * (y[i] + y[i + 1]) * (x[i + 1] - x[i])` as in
+/// [trapezoid_signed_f64](crate::trapezoid_signed_f64), the ordering of `x` is not validated.
+///
+/// Returns NaN if the iterator yields fewer than two items.
+pub fn trapezoid_iter_f64<I: IntoIterator<Item = (f64, f64)>>(iter: I) -> f64 {
+    trapezoid_iter(iter)
+}
+
+/// Integrates `(x, y)` pairs produced lazily, keeping only the previous point.
+///
+/// Every segment contributes `0.5 * (y[i] + y[i + 1]) * (x[i + 1] - x[i])` as in
+/// [trapezoid_signed_f32](crate::trapezoid_signed_f32), the ordering of `x` is not validated.
+///
+/// Returns NaN if the iterator yields fewer than two items.
+pub fn trapezoid_iter_f32<I: IntoIterator<Item = (f32, f32)>>(iter: I) -> f32 {
+    trapezoid_iter(iter)
+}
+
+fn trapezoid_iter<T: TrapezSample, I: IntoIterator<Item = (T, T)>>(iter: I) -> T {
+    let mut stream = StreamingTrapezoid::new();
+    for (x, y) in iter {
+        stream.push(x, y);
+    }
+    if stream.count() < 2 {
+        T::nan()
+    } else {
+        stream.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streaming_trapezoid() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let mut s = StreamingTrapezoid::<f64>::new();
+        assert_eq!(s.value(), 0.);
+        s.push(x[0], y[0]);
+        assert_eq!(s.value(), 0.);
+        for (&x, &y) in x[1..].iter().zip(y[1..].iter()) {
+            s.push(x, y);
+        }
+        assert_eq!(s.value(), 30.5);
+        assert_eq!(s.count(), 6);
+    }
+
+    #[test]
+    fn test_trapezoid_iter() {
+        use crate::trapezoid_f64;
+        let x = [1., 2., 4., 6., 7., 9.];
+        let y = [5., 6., 1., 4., 6., 2.];
+        let lazy = x.iter().copied().zip(y.iter().copied());
+        assert_eq!(trapezoid_iter_f64(lazy), trapezoid_f64(&y, &x));
+        let squares = (0..=10).map(|i| i as f32 * 0.1).map(|x| (x, x * x));
+        assert!((trapezoid_iter_f32(squares) - 0.335).abs() < 1e-6);
+        assert!(trapezoid_iter_f64(core::iter::once((0., 1.))).is_nan());
+    }
+}
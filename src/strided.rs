@@ -0,0 +1,114 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+
+/// Trapezoidal integration of evenly spaced samples interleaved in a larger buffer.
+///
+/// Reads the `count` samples `data[offset + i * stride]`, e.g. one channel of
+/// interleaved audio with `stride` equal to the channel count, without copying them out.
+/// `dx` is the spacing between the selected samples.
+///
+/// Returns NaN if `count < 2`, `stride == 0`, `dx <= 0`
+/// or the last selected sample lies past the end of `data`.
+pub fn trapezoid_even_strided_f64(
+    data: &[f64],
+    offset: usize,
+    stride: usize,
+    count: usize,
+    dx: f64,
+) -> f64 {
+    trapezoid_even_strided(data, offset, stride, count, dx)
+}
+
+/// Trapezoidal integration of evenly spaced samples interleaved in a larger buffer.
+///
+/// Reads the `count` samples `data[offset + i * stride]`, e.g. one channel of
+/// interleaved audio with `stride` equal to the channel count, without copying them out.
+/// `dx` is the spacing between the selected samples.
+///
+/// Returns NaN if `count < 2`, `stride == 0`, `dx <= 0`
+/// or the last selected sample lies past the end of `data`.
+pub fn trapezoid_even_strided_f32(
+    data: &[f32],
+    offset: usize,
+    stride: usize,
+    count: usize,
+    dx: f32,
+) -> f32 {
+    trapezoid_even_strided(data, offset, stride, count, dx)
+}
+
+fn trapezoid_even_strided<T: TrapezSample>(
+    data: &[T],
+    offset: usize,
+    stride: usize,
+    count: usize,
+    dx: T,
+) -> T {
+    if count < 2 || stride == 0 || dx <= T::zero() {
+        return T::nan();
+    }
+    // overflow of the last index counts as out of range
+    let last = (count - 1)
+        .checked_mul(stride)
+        .and_then(|span| span.checked_add(offset));
+    let Some(last) = last.filter(|&last| last < data.len()) else {
+        return T::nan();
+    };
+    let at = |i: usize| data[offset + i * stride];
+    let interior_sum = pairwise_fold(1, count - 1, &|acc, i| acc + at(i));
+    dx * fmla(T::HALF, data[offset] + data[last], interior_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_even_f64;
+
+    #[test]
+    fn test_trapezoid_even_strided() {
+        let left: Vec<f64> = (0..300).map(|i| (i as f64 * 0.01).sin()).collect();
+        let right: Vec<f64> = (0..300).map(|i| (i as f64 * 0.02).cos()).collect();
+        let stereo: Vec<f64> = left
+            .iter()
+            .zip(&right)
+            .flat_map(|(&l, &r)| [l, r])
+            .collect();
+        let strided = trapezoid_even_strided_f64(&stereo, 0, 2, 300, 0.01);
+        assert!((strided - trapezoid_even_f64(&left, 0.01)).abs() < 1e-12);
+        let strided = trapezoid_even_strided_f64(&stereo, 1, 2, 300, 0.01);
+        assert!((strided - trapezoid_even_f64(&right, 0.01)).abs() < 1e-12);
+
+        assert!(trapezoid_even_strided_f64(&stereo, 1, 2, 301, 0.01).is_nan());
+        assert!(trapezoid_even_strided_f32(&[1., 2.], 0, usize::MAX, 2, 1.).is_nan());
+        assert!(trapezoid_even_strided_f32(&[1., 2.], 0, 0, 2, 1.).is_nan());
+    }
+}
@@ -0,0 +1,394 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+
+/// Below this length the pairwise recursion falls back to a straight loop.
+const PAIRWISE_BLOCK: usize = 128;
+/// Block length handed to the vectorized kernels by the pairwise recursion.
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+const SIMD_BLOCK: usize = 2048;
+
+/// Sums `v` with the accumulation strategy selected at build time.
+#[inline]
+pub(crate) fn sum<T: TrapezSample>(v: &[T]) -> T {
+    #[cfg(feature = "high_accuracy")]
+    {
+        neumaier_sum(v)
+    }
+    #[cfg(not(feature = "high_accuracy"))]
+    {
+        #[cfg(all(
+            feature = "simd",
+            not(feature = "deterministic"),
+            any(target_arch = "x86", target_arch = "x86_64")
+        ))]
+        {
+            use crate::avx::{sum_f32_avx2, sum_f64_avx2};
+            use crate::avx512::{sum_f32_avx512, sum_f64_avx512};
+            if has_avx512f()
+                && let Some(s) = simd_sum(v, sum_f64_avx512, sum_f32_avx512)
+            {
+                return s;
+            }
+            if has_avx2()
+                && let Some(s) = simd_sum(v, sum_f64_avx2, sum_f32_avx2)
+            {
+                return s;
+            }
+        }
+        // NEON is part of the aarch64 baseline, no detection needed
+        #[cfg(all(
+            feature = "simd",
+            not(feature = "deterministic"),
+            target_arch = "aarch64"
+        ))]
+        {
+            use crate::neon::{sum_f32_neon, sum_f64_neon};
+            if let Some(s) = simd_sum(v, sum_f64_neon, sum_f32_neon) {
+                return s;
+            }
+        }
+        pairwise_sum(v)
+    }
+}
+
+/// Runs the kernel matching `T` over [SIMD_BLOCK] sized pairwise blocks,
+/// `None` if `T` is neither `f64` nor `f32`.
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+#[inline]
+fn simd_sum<T: TrapezSample>(
+    v: &[T],
+    kernel_f64: unsafe fn(&[f64]) -> f64,
+    kernel_f32: unsafe fn(&[f32]) -> f32,
+) -> Option<T> {
+    use core::any::TypeId;
+    if TypeId::of::<T>() == TypeId::of::<f64>() {
+        // Safety: T is f64, the caller checked the kernel's target feature.
+        let v = unsafe { &*(v as *const [T] as *const [f64]) };
+        let s = pairwise_by(v, SIMD_BLOCK, &|v| unsafe { kernel_f64(v) });
+        return Some(unsafe { core::mem::transmute_copy(&s) });
+    }
+    if TypeId::of::<T>() == TypeId::of::<f32>() {
+        // Safety: T is f32, the caller checked the kernel's target feature.
+        let v = unsafe { &*(v as *const [T] as *const [f32]) };
+        let s = pairwise_by(v, SIMD_BLOCK, &|v| unsafe { kernel_f32(v) });
+        return Some(unsafe { core::mem::transmute_copy(&s) });
+    }
+    None
+}
+
+/// Runtime detection with `std`, otherwise decided by the compile-time target features.
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[inline]
+fn has_avx512f() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::arch::is_x86_feature_detected!("avx512f")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx512f")
+    }
+}
+
+/// Runtime detection with `std`, otherwise decided by the compile-time target features.
+#[cfg(all(
+    feature = "simd",
+    not(feature = "high_accuracy"),
+    not(feature = "deterministic"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[inline]
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::arch::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn naive_sum<T: TrapezSample>(v: &[T]) -> T {
+    let mut sum = T::zero();
+    for &v in v.iter() {
+        sum += v;
+    }
+    sum
+}
+
+/// Cascade summation, the error grows with `log(n)` instead of `n`.
+#[cfg(any(test, not(feature = "high_accuracy")))]
+pub(crate) fn pairwise_sum<T: TrapezSample>(v: &[T]) -> T {
+    pairwise_by(v, PAIRWISE_BLOCK, &chunked_sum)
+}
+
+/// Sums `v` with eight independent accumulators, which breaks the dependency chain
+/// of a single running sum and lets the compiler vectorize the loop.
+#[cfg(any(test, not(feature = "high_accuracy")))]
+#[inline]
+fn chunked_sum<T: TrapezSample>(v: &[T]) -> T {
+    let mut acc = [T::zero(); 8];
+    let chunks = v.chunks_exact(8);
+    let rem = chunks.remainder();
+    for chunk in chunks {
+        for (acc, &v) in acc.iter_mut().zip(chunk.iter()) {
+            *acc += v;
+        }
+    }
+    let mut sum = ((acc[0] + acc[4]) + (acc[1] + acc[5])) + ((acc[2] + acc[6]) + (acc[3] + acc[7]));
+    for &v in rem.iter() {
+        sum += v;
+    }
+    sum
+}
+
+/// Splits `v` in halves until at most `block` elements remain and sums those with `base`.
+#[cfg(any(test, not(feature = "high_accuracy")))]
+fn pairwise_by<T: TrapezSample>(v: &[T], block: usize, base: &impl Fn(&[T]) -> T) -> T {
+    if v.len() <= block {
+        return base(v);
+    }
+    let (lo, hi) = v.split_at(v.len() / 2);
+    pairwise_by(lo, block, base) + pairwise_by(hi, block, base)
+}
+
+/// Pairwise reduction of terms `start..end` that are produced on the fly.
+///
+/// In the base case terms are accumulated by `fold(acc, i)`, which allows
+/// fused multiply-add accumulation of the terms.
+pub(crate) fn pairwise_fold<T: TrapezSample>(
+    start: usize,
+    end: usize,
+    fold: &impl Fn(T, usize) -> T,
+) -> T {
+    pairwise_range(start, end, &|start, end| {
+        let mut acc = T::zero();
+        for i in start..end {
+            acc = fold(acc, i);
+        }
+        acc
+    })
+}
+
+/// [sum] of the terms `start..end` produced by `term`, without a slice to hold them.
+///
+/// Terms are projected into stack blocks of [PAIRWISE_BLOCK] and summed there, the
+/// result matches [sum] over the collected terms except for the block boundaries
+/// handed to the `simd` kernels.
+#[cfg(feature = "num-complex")]
+pub(crate) fn sum_by<T: TrapezSample>(start: usize, end: usize, term: &impl Fn(usize) -> T) -> T {
+    #[cfg(feature = "high_accuracy")]
+    {
+        neumaier_fold((start..end).map(term))
+    }
+    #[cfg(not(feature = "high_accuracy"))]
+    {
+        pairwise_range(start, end, &|start, end| {
+            let mut block = [T::zero(); PAIRWISE_BLOCK];
+            let block = &mut block[..end - start];
+            for (v, i) in block.iter_mut().zip(start..end) {
+                *v = term(i);
+            }
+            sum(block)
+        })
+    }
+}
+
+/// Splits `start..end` in halves until at most [PAIRWISE_BLOCK] indices remain
+/// and reduces those with `base`.
+fn pairwise_range<T: TrapezSample>(
+    start: usize,
+    end: usize,
+    base: &impl Fn(usize, usize) -> T,
+) -> T {
+    if end - start <= PAIRWISE_BLOCK {
+        return base(start, end);
+    }
+    let mid = start + (end - start) / 2;
+    pairwise_range(start, mid, base) + pairwise_range(mid, end, base)
+}
+
+/// Sum of the trapezoid areas `dx[i] * (y[i] + y[i + 1]) / 2` over all segments of `x`.
+///
+/// Every pairwise block first stores its differences of `x` in a stack buffer and then
+/// runs [fused_segments] over them, so neither loop interleaves reads of `y` and `x`.
+/// Gives bitwise the same result as [segment_sum_dx] with `dx[i] = x[i + 1] - x[i]`.
+#[inline]
+pub(crate) fn segment_sum<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    segment_sum_inspect(y, x, &|_| {})
+}
+
+/// [segment_sum] handing every block of differences to `inspect` while it is
+/// still in the stack buffer, lets callers check `x` without a second pass.
+pub(crate) fn segment_sum_inspect<T: TrapezSample>(y: &[T], x: &[T], inspect: &impl Fn(&[T])) -> T {
+    pairwise_range(0, y.len() - 1, &|start, end| {
+        let mut dx = [T::zero(); PAIRWISE_BLOCK];
+        let dx = &mut dx[..end - start];
+        for (d, x) in dx.iter_mut().zip(x[start..=end].windows(2)) {
+            *d = x[1] - x[0];
+        }
+        inspect(dx);
+        fused_segments(&y[start..=end], dx)
+    })
+}
+
+/// [segment_sum] of the samples produced by `y` over `x`, bitwise the same result
+/// as [segment_sum] over the collected samples.
+#[cfg(feature = "num-complex")]
+pub(crate) fn segment_sum_by<T: TrapezSample>(x: &[T], y: &impl Fn(usize) -> T) -> T {
+    pairwise_range(0, x.len() - 1, &|start, end| {
+        let mut dx = [T::zero(); PAIRWISE_BLOCK];
+        let mut ys = [T::zero(); PAIRWISE_BLOCK + 1];
+        let dx = &mut dx[..end - start];
+        let ys = &mut ys[..=end - start];
+        for (d, x) in dx.iter_mut().zip(x[start..=end].windows(2)) {
+            *d = x[1] - x[0];
+        }
+        for (v, i) in ys.iter_mut().zip(start..=end) {
+            *v = y(i);
+        }
+        fused_segments(ys, dx)
+    })
+}
+
+/// [segment_sum] over precomputed differences, `dx.len()` must be `y.len() - 1`.
+pub(crate) fn segment_sum_dx<T: TrapezSample>(y: &[T], dx: &[T]) -> T {
+    pairwise_range(0, dx.len(), &|start, end| {
+        fused_segments(&y[start..=end], &dx[start..end])
+    })
+}
+
+/// `sum(dx[i] * (y[i] + y[i + 1])) / 2` with eight independent accumulators,
+/// `y` holds one sample more than `dx`.
+#[inline]
+fn fused_segments<T: TrapezSample>(y: &[T], dx: &[T]) -> T {
+    let mut acc = [T::zero(); 8];
+    let dx_chunks = dx.chunks_exact(8);
+    let lo_chunks = y[..dx.len()].chunks_exact(8);
+    let hi_chunks = y[1..].chunks_exact(8);
+    let tail = dx.len() - dx_chunks.remainder().len();
+    for ((dx, lo), hi) in dx_chunks.zip(lo_chunks).zip(hi_chunks) {
+        for (j, acc) in acc.iter_mut().enumerate() {
+            *acc = fmla(dx[j], lo[j] + hi[j], *acc);
+        }
+    }
+    let mut sum = ((acc[0] + acc[4]) + (acc[1] + acc[5])) + ((acc[2] + acc[6]) + (acc[3] + acc[7]));
+    for i in tail..dx.len() {
+        sum = fmla(dx[i], y[i] + y[i + 1], sum);
+    }
+    sum * T::HALF
+}
+
+/// Neumaier's improved Kahan summation, the running compensation also
+/// captures the low-order bits lost when an addend exceeds the partial sum.
+#[cfg(any(test, feature = "high_accuracy"))]
+#[inline]
+pub(crate) fn neumaier_sum<T: TrapezSample>(v: &[T]) -> T {
+    neumaier_fold(v.iter().copied())
+}
+
+/// [neumaier_sum] over terms produced on the fly.
+pub(crate) fn neumaier_fold<T: TrapezSample>(v: impl Iterator<Item = T>) -> T {
+    let mut sum = T::zero();
+    let mut c = T::zero();
+    for v in v {
+        let t = sum + v;
+        if sum.abs() >= v.abs() {
+            c += (sum - t) + v;
+        } else {
+            c += (v - t) + sum;
+        }
+        sum = t;
+    }
+    // an overflowed sum has no meaningful compensation, `inf - inf` would turn it into NaN
+    if sum.is_finite() { sum + c } else { sum }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neumaier_sum() {
+        let v = vec![0.1f64; 10_000_000];
+        let exact = 1_000_000f64;
+        let compensated = neumaier_sum(&v);
+        let naive = naive_sum(&v);
+        assert_eq!(compensated, exact);
+        assert!((naive - exact).abs() > 1e-6);
+        // the classic case plain Kahan gets wrong
+        assert_eq!(neumaier_sum(&[1., 1e100, 1., -1e100]), 2.);
+    }
+
+    #[test]
+    fn test_pairwise_sum() {
+        // large values interleaved with many small ones
+        let v: Vec<f32> = (0..1_000_000)
+            .map(|i| {
+                if i % 1000 == 0 {
+                    1e4
+                } else {
+                    1e-3 + (i % 7) as f32 * 1e-4
+                }
+            })
+            .collect();
+        let reference: f64 = v.iter().map(|&x| x as f64).sum();
+        let pairwise_err = (pairwise_sum(&v) as f64 - reference).abs();
+        let naive_err = (naive_sum(&v) as f64 - reference).abs();
+        assert!(
+            pairwise_err * 10. < naive_err,
+            "{pairwise_err} vs {naive_err}"
+        );
+        let folded = pairwise_fold(0, v.len(), &|acc: f32, i| acc + v[i]);
+        assert!((folded as f64 - reference).abs() <= 10. * pairwise_err.max(1e-3));
+        // reassociation by the chunked base case stays within rounding of the plain loop
+        let short: Vec<f64> = (0..PAIRWISE_BLOCK - 3).map(|i| (i as f64).sqrt()).collect();
+        let plain = naive_sum(&short);
+        assert!((chunked_sum(&short) - plain).abs() <= 1e-12 * plain);
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::trapezoid::{classify, validate};
+
+/// Integrals of the even and odd parts of `y` around the sample `center`.
+///
+/// With `c = center`, samples are paired by index as `y[c - k]` and `y[c + k]` for
+/// `k = 0..=m`, where `m = min(c, n - 1 - c)`. When `c` is not the middle of the array
+/// the range is clipped to the shorter side and the samples beyond `x[c - m]` or
+/// `x[c + m]` are ignored. The offset of pair `k` is `|x[c + k] - x[c - k]| / 2`, which is
+/// exact on a grid symmetric about `x[c]` and averages the two sides otherwise.
+///
+/// The even part `(y[c + k] + y[c - k]) / 2` is integrated over the whole symmetric range,
+/// so it equals the trapezoid integral of `y` between `x[c - m]` and `x[c + m]` on a
+/// symmetric grid. The odd part `(y[c + k] - y[c - k]) / 2` always integrates to zero over
+/// that range, its integral is taken over the right half instead: half the difference
+/// between the areas right and left of `x[c]`. Descending abscissas give the same values
+/// as ascending ones.
+///
+/// # Returns
+/// `(even, odd)`, both NaN if the input is invalid, `center` is out of bounds or
+/// there is no sample on one of its sides.
+pub fn trapezoid_symmetric_parts_f64(y: &[f64], x: &[f64], center: usize) -> (f64, f64) {
+    trapezoid_symmetric_parts(y, x, center)
+}
+
+/// Integrals of the even and odd parts of `y` around the sample `center`.
+///
+/// With `c = center`, samples are paired by index as `y[c - k]` and `y[c + k]` for
+/// `k = 0..=m`, where `m = min(c, n - 1 - c)`. When `c` is not the middle of the array
+/// the range is clipped to the shorter side and the samples beyond `x[c - m]` or
+/// `x[c + m]` are ignored. The offset of pair `k` is `|x[c + k] - x[c - k]| / 2`, which is
+/// exact on a grid symmetric about `x[c]` and averages the two sides otherwise.
+///
+/// The even part `(y[c + k] + y[c - k]) / 2` is integrated over the whole symmetric range,
+/// so it equals the trapezoid integral of `y` between `x[c - m]` and `x[c + m]` on a
+/// symmetric grid. The odd part `(y[c + k] - y[c - k]) / 2` always integrates to zero over
+/// that range, its integral is taken over the right half instead: half the difference
+/// between the areas right and left of `x[c]`. Descending abscissas give the same values
+/// as ascending ones.
+///
+/// # Returns
+/// `(even, odd)`, both NaN if the input is invalid, `center` is out of bounds or
+/// there is no sample on one of its sides.
+pub fn trapezoid_symmetric_parts_f32(y: &[f32], x: &[f32], center: usize) -> (f32, f32) {
+    trapezoid_symmetric_parts(y, x, center)
+}
+
+fn trapezoid_symmetric_parts<T: TrapezSample>(y: &[T], x: &[T], center: usize) -> (T, T) {
+    if validate(y, x).is_err() || classify(x).is_err() || center >= y.len() {
+        return (T::nan(), T::nan());
+    }
+    let m = center.min(y.len() - 1 - center);
+    if m == 0 {
+        return (T::nan(), T::nan());
+    }
+    let part = |k: usize| {
+        let (left, right) = (y[center - k], y[center + k]);
+        let t = (x[center + k] - x[center - k]).abs() * T::HALF;
+        (t, (right + left) * T::HALF, (right - left) * T::HALF)
+    };
+    let (mut even, mut odd) = (T::zero(), T::zero());
+    let mut prev = part(0);
+    for k in 1..=m {
+        let next = part(k);
+        let half_dt = (next.0 - prev.0) * T::HALF;
+        even = fmla(half_dt, prev.1 + next.1, even);
+        odd = fmla(half_dt, prev.2 + next.2, odd);
+        prev = next;
+    }
+    (even + even, odd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_parts() {
+        let x: Vec<f64> = (0..9).map(|i| i as f64 * 0.5 - 2.).collect();
+        let y: Vec<f64> = x.iter().map(|x| x * x + 1.).collect();
+        let (even, odd) = trapezoid_symmetric_parts_f64(&y, &x, 4);
+        assert!((even - crate::trapezoid_f64(&y, &x)).abs() < 1e-14);
+        assert!(odd.abs() < 1e-15);
+        // y = x on [-1, 2] around x = 0: the range is clipped to [-1, 1]
+        let x = [-1., 0., 1., 2.];
+        let (even, odd) = trapezoid_symmetric_parts_f32(&x, &x, 1);
+        assert_eq!((even, odd), (0., 0.5));
+        assert!(
+            trapezoid_symmetric_parts_f64(&[1., 2.], &[0., 1.], 0)
+                .0
+                .is_nan()
+        );
+    }
+}
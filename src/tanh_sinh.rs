@@ -0,0 +1,184 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use num_traits::{AsPrimitive, Float};
+
+/// Number of step halvings before giving up, level `l` uses the step `2^-l`.
+const TANH_SINH_MAX_LEVEL: usize = 12;
+/// Levels always performed before the estimate may be accepted.
+const TANH_SINH_MIN_LEVEL: usize = 2;
+/// Truncation of the transformed axis, the weights are far below any precision beyond it.
+const TANH_SINH_T_MAX: f64 = 6.5;
+
+/// Integrates `f` over `[a, b]` with tanh-sinh (double exponential) quadrature.
+///
+/// The substitution `x = (a + b) / 2 + (b - a) / 2 * tanh(π / 2 * sinh(t))` makes the
+/// integrand decay double exponentially, so integrable endpoint singularities such as
+/// `1 / sqrt(x)` at zero are handled well. The trapezoid step in `t` is halved until two
+/// successive estimates differ by at most the absolute tolerance `tol`.
+///
+/// `f` is never evaluated at `a` or `b`, samples that would round onto an endpoint are dropped.
+///
+/// Returns NaN if the estimate did not settle within the level limit,
+/// see [try_tanh_sinh_f64] for the error reporting variant.
+pub fn tanh_sinh_f64<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, tol: f64) -> f64 {
+    try_tanh_sinh(f, a, b, tol).unwrap_or(f64::NAN)
+}
+
+/// Integrates `f` over `[a, b]` with tanh-sinh (double exponential) quadrature.
+///
+/// The substitution `x = (a + b) / 2 + (b - a) / 2 * tanh(π / 2 * sinh(t))` makes the
+/// integrand decay double exponentially, so integrable endpoint singularities such as
+/// `1 / sqrt(x)` at zero are handled well. The trapezoid step in `t` is halved until two
+/// successive estimates differ by at most the absolute tolerance `tol`.
+///
+/// `f` is never evaluated at `a` or `b`, samples that would round onto an endpoint are dropped.
+///
+/// Returns NaN if the estimate did not settle within the level limit,
+/// see [try_tanh_sinh_f32] for the error reporting variant.
+pub fn tanh_sinh_f32<F: Fn(f32) -> f32>(f: F, a: f32, b: f32, tol: f32) -> f32 {
+    try_tanh_sinh(f, a, b, tol).unwrap_or(f32::NAN)
+}
+
+/// Checked version of [tanh_sinh_f64].
+///
+/// # Errors
+/// [IntegrateError::NotConverged] if the estimate did not settle within the level limit.
+pub fn try_tanh_sinh_f64<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+) -> Result<f64, IntegrateError> {
+    try_tanh_sinh(f, a, b, tol)
+}
+
+/// Checked version of [tanh_sinh_f32].
+///
+/// # Errors
+/// [IntegrateError::NotConverged] if the estimate did not settle within the level limit.
+pub fn try_tanh_sinh_f32<F: Fn(f32) -> f32>(
+    f: F,
+    a: f32,
+    b: f32,
+    tol: f32,
+) -> Result<f32, IntegrateError> {
+    try_tanh_sinh(f, a, b, tol)
+}
+
+fn try_tanh_sinh<T: TrapezSample, F: Fn(T) -> T>(
+    f: F,
+    a: T,
+    b: T,
+    tol: T,
+) -> Result<T, IntegrateError>
+where
+    f64: AsPrimitive<T>,
+{
+    let half = (b - a) * T::HALF;
+    if half == T::zero() {
+        return Ok(T::zero());
+    }
+    // level 0 with unit step, the centre node has weight π / 2
+    let mut sum = fmla(
+        core::f64::consts::FRAC_PI_2.as_(),
+        f((a + b) * T::HALF),
+        level_sum(&f, a, b, 1, 1),
+    );
+    let mut estimate = half * sum;
+    for level in 1..=TANH_SINH_MAX_LEVEL {
+        let scale = 1usize << level;
+        sum += level_sum(&f, a, b, scale, 2);
+        let h: T = (1. / scale as f64).as_();
+        let refined = half * h * sum;
+        if level >= TANH_SINH_MIN_LEVEL && (refined - estimate).abs() <= tol {
+            return Ok(refined);
+        }
+        estimate = refined;
+    }
+    Err(IntegrateError::NotConverged)
+}
+
+/// Sum of the weighted samples at `t = ±k / scale` for `k = 1, 1 + stride, ...`.
+fn level_sum<T: TrapezSample, F: Fn(T) -> T>(f: &F, a: T, b: T, scale: usize, stride: usize) -> T
+where
+    f64: AsPrimitive<T>,
+{
+    let half = (b - a) * T::HALF;
+    let mut sum = T::zero();
+    // each side stops on its own, near zero an endpoint keeps much finer samples
+    let (mut use_left, mut use_right) = (true, true);
+    let mut k = 1;
+    while (use_left || use_right) && (k as f64) <= TANH_SINH_T_MAX * scale as f64 {
+        let t = k as f64 / scale as f64;
+        let u = core::f64::consts::FRAC_PI_2 * Float::sinh(t);
+        // distance to the endpoints in units of `half`, 1 - tanh(u) without cancellation
+        let gap: T = (2. / (1. + Float::exp(2. * u))).as_();
+        let weight: T =
+            (core::f64::consts::FRAC_PI_2 * Float::cosh(t) / Float::powi(Float::cosh(u), 2)).as_();
+        let left = fmla(half, gap, a);
+        let right = fmla(-half, gap, b);
+        if weight == T::zero() {
+            break;
+        }
+        use_left &= left != a;
+        use_right &= right != b;
+        if use_left {
+            sum = fmla(weight, f(left), sum);
+        }
+        if use_right {
+            sum = fmla(weight, f(right), sum);
+        }
+        k += stride;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_fn_f64;
+
+    #[test]
+    fn test_tanh_sinh_singular() {
+        let f = |x: f64| {
+            assert!(x > 0. && x < 1., "endpoint evaluated");
+            1. / x.sqrt()
+        };
+        let result = tanh_sinh_f64(f, 0., 1., 1e-10);
+        assert!((result - 2.).abs() < 1e-9, "{result}");
+        // the plain rule hits the singularity at x = 0
+        assert!(trapezoid_fn_f64(|x| 1. / x.sqrt(), 0., 1., 1000).is_infinite());
+
+        let log = tanh_sinh_f32(|x| x.ln(), 0., 1., 1e-5);
+        assert!((log + 1.).abs() < 1e-5, "{log}");
+    }
+}
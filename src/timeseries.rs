@@ -0,0 +1,90 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::mla::fmla;
+use crate::sum::{pairwise_fold, sum};
+use crate::trapezoid::classify_iter;
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Integrates `y` sampled at the timestamps `t` with the trapezoidal rule.
+///
+/// Segment widths are the fractional seconds between consecutive timestamps, so the
+/// result is in value-seconds, e.g. watts over time give joules. `t` follows the rules
+/// of [trapezoid_f64](crate::trapezoid_f64): ascending or descending, with evenly
+/// spaced timestamps taking the uniform spacing path.
+///
+/// Returns NaN if the lengths differ, fewer than two samples are given
+/// or the timestamps are not monotonic.
+pub fn trapezoid_timeseries_f64(y: &[f64], t: &[DateTime<Utc>]) -> f64 {
+    if y.len() != t.len() || y.len() < 2 {
+        return f64::NAN;
+    }
+    let t0 = t[0];
+    let offset = |ti: &DateTime<Utc>| seconds(ti.signed_duration_since(t0));
+    let Ok(grid) = classify_iter(t.iter().map(offset), f64::TOLERANCE) else {
+        return f64::NAN;
+    };
+    let n = y.len();
+    let integral = if let Some(h0) = grid.step {
+        h0 * fmla(y[0] + y[n - 1], 0.5, sum(&y[1..n - 1]))
+    } else {
+        pairwise_fold(0, n - 1, &|acc, i| {
+            let dt = seconds(t[i + 1].signed_duration_since(t[i]));
+            fmla(dt * 0.5, y[i] + y[i + 1], acc)
+        })
+    };
+    if grid.descending { -integral } else { integral }
+}
+
+fn seconds(d: TimeDelta) -> f64 {
+    d.num_seconds() as f64 + d.subsec_nanos() as f64 * 1e-9
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_timeseries() {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let offsets = [0., 1.5, 4., 6.25, 7., 9.];
+        let t: Vec<DateTime<Utc>> = offsets
+            .iter()
+            .map(|&s| start + TimeDelta::milliseconds((s * 1000.) as i64))
+            .collect();
+        let y = [5., 6., 1., 4., 6., 2.];
+        let expected = trapezoid_f64(&y, &offsets);
+        assert!((trapezoid_timeseries_f64(&y, &t) - expected).abs() < 1e-12);
+
+        let even: Vec<DateTime<Utc>> = (0..6).map(|i| start + TimeDelta::seconds(2 * i)).collect();
+        assert_eq!(trapezoid_timeseries_f64(&y, &even), 41.);
+        assert!(trapezoid_timeseries_f64(&y[1..], &t).is_nan());
+    }
+}
@@ -0,0 +1,1344 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use crate::promote::try_trapezoid_promoted;
+use crate::sum::{segment_sum, segment_sum_dx, segment_sum_inspect, sum};
+
+/// Calculates the definite integral of a dataset using the trapezoidal rule.
+///
+/// This implementation handles non-uniform spacing between x-values by
+/// calculating the area of each trapezoidal segment directly.
+///
+/// `x` must be monotonic. Descending abscissas are integrated as if the samples
+/// were reversed into ascending order, so the result does not depend on the
+/// direction of the sweep. Use [trapezoid_signed_f64] for an orientation
+/// dependent result.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of monotonic abscissas.
+///
+/// # Returns
+/// The approximate definite integral (area under the curve),
+/// or NaN if the input is invalid (see [try_trapezoid_f64]).
+pub fn trapezoid_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid(y, x)
+}
+
+/// Calculates the definite integral of a dataset using the trapezoidal rule.
+///
+/// This implementation handles non-uniform spacing between x-values by
+/// calculating the area of each trapezoidal segment directly.
+///
+/// `x` must be monotonic. Descending abscissas are integrated as if the samples
+/// were reversed into ascending order, so the result does not depend on the
+/// direction of the sweep. Use [trapezoid_signed_f32] for an orientation
+/// dependent result.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of monotonic abscissas.
+///
+/// # Returns
+/// The approximate definite integral (area under the curve),
+/// or NaN if the input is invalid (see [try_trapezoid_f32]).
+pub fn trapezoid_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid(y, x)
+}
+
+/// Checked version of [trapezoid_f64].
+///
+/// The monotonicity of `x` is verified in the same pass that detects uniform spacing.
+/// Repeated adjacent abscissas are accepted, the zero-width segment they form
+/// contributes nothing to the integral.
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::LengthMismatch] if `y` and `x` lengths differ.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+/// * [IntegrateError::Overflow] if all samples are finite but the accumulated sum is not.
+/// * [IntegrateError::Divergent] if a sample is infinite, NaN samples give a NaN result.
+pub fn try_trapezoid_f64(y: &[f64], x: &[f64]) -> Result<f64, IntegrateError> {
+    try_trapezoid(y, x).and_then(|v| detect_overflow(v, y, x))
+}
+
+/// Calculates the signed definite integral of a dataset using the trapezoidal rule.
+///
+/// Every segment contributes `0.5 * (y[i] + y[i + 1]) * (x[i + 1] - x[i])`,
+/// so descending abscissas produce the negated integral, just like integrating
+/// from a larger to a smaller bound. The ordering of `x` is not validated.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of abscissas.
+///
+/// # Returns
+/// The approximate signed integral, or NaN if `y` has fewer than two samples
+/// or the lengths of `y` and `x` differ.
+pub fn trapezoid_signed_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_signed(y, x)
+}
+
+/// Checked version of [trapezoid_f32].
+///
+/// The monotonicity of `x` is verified in the same pass that detects uniform spacing.
+/// Repeated adjacent abscissas are accepted, the zero-width segment they form
+/// contributes nothing to the integral.
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::LengthMismatch] if `y` and `x` lengths differ.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+/// * [IntegrateError::Overflow] if all samples are finite but the accumulated sum is not.
+/// * [IntegrateError::Divergent] if a sample is infinite, NaN samples give a NaN result.
+pub fn try_trapezoid_f32(y: &[f32], x: &[f32]) -> Result<f32, IntegrateError> {
+    try_trapezoid(y, x).and_then(|v| detect_overflow(v, y, x))
+}
+
+/// Lazily yields the area `0.5 * (y[i] + y[i + 1]) * (x[i + 1] - x[i])` of every segment.
+///
+/// The areas are signed like in [trapezoid_signed_f64], for ascending abscissas their sum
+/// is [trapezoid_f64] up to rounding. Nothing is validated, the iterator is empty if the
+/// lengths of `y` and `x` differ.
+///
+/// ```
+/// use trapez_integrate::trapezoid_segment_areas_f64;
+///
+/// let y = [1., -2., -1., 3.];
+/// let x = [0., 1., 2., 3.];
+/// let positive: f64 = trapezoid_segment_areas_f64(&y, &x).filter(|&a| a > 0.).sum();
+/// assert_eq!(positive, 1.);
+/// ```
+pub fn trapezoid_segment_areas_f64<'a>(
+    y: &'a [f64],
+    x: &'a [f64],
+) -> impl Iterator<Item = f64> + 'a {
+    segment_areas(y, x)
+}
+
+/// Lazily yields the area `0.5 * (y[i] + y[i + 1]) * (x[i + 1] - x[i])` of every segment.
+///
+/// The areas are signed like in [trapezoid_signed_f32], for ascending abscissas their sum
+/// is [trapezoid_f32] up to rounding. Nothing is validated, the iterator is empty if the
+/// lengths of `y` and `x` differ.
+pub fn trapezoid_segment_areas_f32<'a>(
+    y: &'a [f32],
+    x: &'a [f32],
+) -> impl Iterator<Item = f32> + 'a {
+    segment_areas(y, x)
+}
+
+fn segment_areas<'a, T: TrapezSample>(y: &'a [T], x: &'a [T]) -> impl Iterator<Item = T> + 'a {
+    // a mismatch yields no windows rather than silently pairing a prefix
+    let n = if y.len() == x.len() { y.len() } else { 0 };
+    y[..n]
+        .windows(2)
+        .zip(x[..n].windows(2))
+        .map(|(y, x)| add_segment(T::zero(), y, x))
+}
+
+/// [trapezoid_f64] treating zero or one sample as an empty integral.
+///
+/// Accumulating code, e.g. a stream that has seen a single sample, can use the
+/// result directly instead of special casing short inputs. Differing lengths
+/// and non-monotonic `x` still give NaN.
+pub fn trapezoid_or_zero_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_or_zero(y, x)
+}
+
+/// [trapezoid_f32] treating zero or one sample as an empty integral.
+///
+/// Accumulating code, e.g. a stream that has seen a single sample, can use the
+/// result directly instead of special casing short inputs. Differing lengths
+/// and non-monotonic `x` still give NaN.
+pub fn trapezoid_or_zero_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_or_zero(y, x)
+}
+
+fn trapezoid_or_zero<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    match try_trapezoid(y, x) {
+        Ok(v) => v,
+        Err(IntegrateError::TooFewPoints) => T::zero(),
+        Err(_) => T::nan(),
+    }
+}
+
+/// [trapezoid_f64] with a custom relative tolerance for the uniform spacing detection.
+///
+/// Every interval within `rel_tol * max(|x[1] - x[0]|, 1)` of the first one counts as
+/// uniform, a loose tolerance lets abscissas carrying accumulated rounding error take
+/// the evenly spaced path. [trapezoid_f64] uses `1e-12`.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f64]).
+pub fn trapezoid_with_tol_f64(y: &[f64], x: &[f64], rel_tol: f64) -> f64 {
+    try_trapezoid_with_tol(y, x, rel_tol).unwrap_or(f64::NAN)
+}
+
+/// [trapezoid_f32] with a custom relative tolerance for the uniform spacing detection.
+///
+/// Every interval within `rel_tol * max(|x[1] - x[0]|, 1)` of the first one counts as
+/// uniform, a loose tolerance lets abscissas carrying accumulated rounding error take
+/// the evenly spaced path. [trapezoid_f32] uses `1e-6`.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f32]).
+pub fn trapezoid_with_tol_f32(y: &[f32], x: &[f32], rel_tol: f32) -> f32 {
+    try_trapezoid_with_tol(y, x, rel_tol).unwrap_or(f32::NAN)
+}
+
+/// [trapezoid_f32] returning `f64`, with every sum and product evaluated in `f64`.
+///
+/// Samples are widened one at a time as they are read, so the input keeps its `f32`
+/// memory footprint while the result carries `f64` rounding. Uniform spacing is
+/// detected on the widened abscissas with the `f64` tolerance.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f32]).
+pub fn trapezoid_f32_acc64(y: &[f32], x: &[f32]) -> f64 {
+    try_trapezoid_promoted(y, x, f64::from, f64::from).unwrap_or(f64::NAN)
+}
+
+/// [trapezoid_f64] over `f32` samples and `f64` abscissas.
+///
+/// Every sample is widened to `f64` as it is read, so high resolution timestamps
+/// can be paired with compact amplitudes without converting `y` first.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f64]).
+pub fn trapezoid_mixed_f64(y: &[f32], x: &[f64]) -> f64 {
+    try_trapezoid_promoted(y, x, f64::from, |x| x).unwrap_or(f64::NAN)
+}
+
+/// [trapezoid_f64] in a single pass over the input.
+///
+/// [trapezoid_f64] scans `x` once to check monotonicity and detect uniform spacing
+/// before integrating, which doubles the traffic for inputs far larger than the
+/// cache, e.g. memory-mapped files. Here the monotonicity check runs on the interval
+/// widths while they are computed for the integration, at the cost of never taking
+/// the cheaper evenly spaced path. On non-uniform grids the result is bitwise the
+/// same as [trapezoid_f64], on uniform grids it agrees up to rounding.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f64]).
+pub fn trapezoid_single_pass_f64(y: &[f64], x: &[f64]) -> f64 {
+    trapezoid_single_pass(y, x)
+}
+
+/// [trapezoid_f32] in a single pass over the input.
+///
+/// [trapezoid_f32] scans `x` once to check monotonicity and detect uniform spacing
+/// before integrating, which doubles the traffic for inputs far larger than the
+/// cache, e.g. memory-mapped files. Here the monotonicity check runs on the interval
+/// widths while they are computed for the integration, at the cost of never taking
+/// the cheaper evenly spaced path. On non-uniform grids the result is bitwise the
+/// same as [trapezoid_f32], on uniform grids it agrees up to rounding.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f32]).
+pub fn trapezoid_single_pass_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_single_pass(y, x)
+}
+
+fn trapezoid_single_pass<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    use core::cell::Cell;
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    let (rising, falling) = (Cell::new(false), Cell::new(false));
+    let integral = segment_sum_inspect(y, x, &|dx| {
+        rising.set(rising.get() || dx.iter().any(|&d| d > T::zero()));
+        falling.set(falling.get() || dx.iter().any(|&d| d < T::zero()));
+    });
+    match (rising.get(), falling.get()) {
+        (true, true) => T::nan(),
+        (false, true) => -integral,
+        _ => integral,
+    }
+}
+
+/// [trapezoid_f64] over integer samples such as counts.
+///
+/// Every sample is converted with `Into<f64>` as it is read, which covers the
+/// integer types up to 32 bits exactly, no float copy of `y` is made.
+///
+/// Returns NaN if the input is invalid (see [try_trapezoid_f64]).
+pub fn trapezoid_int_f64<I: Into<f64> + Copy>(y: &[I], x: &[f64]) -> f64 {
+    try_trapezoid_promoted(y, x, I::into, |x| x).unwrap_or(f64::NAN)
+}
+
+/// Checked version of [trapezoid_f32] accumulating in `f64`.
+///
+/// Samples are widened to `f64` as they are read, so intermediate sums of large
+/// magnitude do not overflow as long as the final integral fits into `f32`.
+///
+/// # Errors
+/// Same as [try_trapezoid_f32], [IntegrateError::Overflow] is reported
+/// only when the integral itself exceeds the `f32` range.
+pub fn try_trapezoid_wide_f32(y: &[f32], x: &[f32]) -> Result<f32, IntegrateError> {
+    let wide = try_trapezoid_promoted(y, x, f64::from, f64::from)?;
+    detect_overflow(wide as f32, y, x)
+}
+
+/// Calculates the signed definite integral of a dataset using the trapezoidal rule.
+///
+/// Every segment contributes `0.5 * (y[i] + y[i + 1]) * (x[i + 1] - x[i])`,
+/// so descending abscissas produce the negated integral, just like integrating
+/// from a larger to a smaller bound. The ordering of `x` is not validated.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of abscissas.
+///
+/// # Returns
+/// The approximate signed integral, or NaN if `y` has fewer than two samples
+/// or the lengths of `y` and `x` differ.
+pub fn trapezoid_signed_f32(y: &[f32], x: &[f32]) -> f32 {
+    trapezoid_signed(y, x)
+}
+
+/// Calculates the definite integral of a dataset using the trapezoidal rule.
+///
+/// Generic version of [trapezoid_f32] and [trapezoid_f64] for use in code
+/// that is itself generic over the sample type.
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `x` - The array of abscissas.
+///
+/// # Returns
+/// The approximate definite integral (area under the curve), or NaN if the input is invalid.
+pub fn trapezoid<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    try_trapezoid(y, x).unwrap_or(T::nan())
+}
+
+/// [trapezoid] over any containers that can be viewed as slices,
+/// so owned and borrowed inputs can be passed interchangeably.
+///
+/// ```
+/// use trapez_integrate::trapezoid_any;
+///
+/// let y = vec![5., 6., 1., 4., 6., 2.];
+/// let x = [1., 2., 4., 6., 7., 9.];
+/// assert_eq!(trapezoid_any(&y, x), 30.5);
+/// assert_eq!(trapezoid_any(y.into_boxed_slice(), &x[..]), 30.5);
+/// ```
+pub fn trapezoid_any<T: TrapezSample, A: AsRef<[T]>, B: AsRef<[T]>>(y: A, x: B) -> T {
+    trapezoid(y.as_ref(), x.as_ref())
+}
+
+/// How the checked path treats repeated adjacent abscissas `x[i + 1] == x[i]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateXPolicy {
+    /// Accept them, the zero-width segment contributes nothing.
+    /// This reproduces [try_trapezoid_f64] and [try_trapezoid_f32].
+    #[default]
+    Allow,
+    /// Reject them with [IntegrateError::DuplicateAbscissa].
+    Error,
+    /// Accept them like [DuplicateXPolicy::Allow], with the `log` feature enabled
+    /// a warning naming the first duplicate is emitted through the `log` crate.
+    Warn,
+}
+
+/// [try_trapezoid_f64] with explicit handling of repeated adjacent abscissas.
+///
+/// # Errors
+/// Same as [try_trapezoid_f64], and [IntegrateError::DuplicateAbscissa] with the first
+/// index `i` where `x[i + 1] == x[i]` under [DuplicateXPolicy::Error].
+pub fn try_trapezoid_with_duplicates_f64(
+    y: &[f64],
+    x: &[f64],
+    policy: DuplicateXPolicy,
+) -> Result<f64, IntegrateError> {
+    try_trapezoid_with_duplicates(y, x, policy).and_then(|v| detect_overflow(v, y, x))
+}
+
+/// [try_trapezoid_f32] with explicit handling of repeated adjacent abscissas.
+///
+/// # Errors
+/// Same as [try_trapezoid_f32], and [IntegrateError::DuplicateAbscissa] with the first
+/// index `i` where `x[i + 1] == x[i]` under [DuplicateXPolicy::Error].
+pub fn try_trapezoid_with_duplicates_f32(
+    y: &[f32],
+    x: &[f32],
+    policy: DuplicateXPolicy,
+) -> Result<f32, IntegrateError> {
+    try_trapezoid_with_duplicates(y, x, policy).and_then(|v| detect_overflow(v, y, x))
+}
+
+fn try_trapezoid_with_duplicates<T: TrapezSample>(
+    y: &[T],
+    x: &[T],
+    policy: DuplicateXPolicy,
+) -> Result<T, IntegrateError> {
+    validate(y, x)?;
+    let duplicate = match policy {
+        DuplicateXPolicy::Allow => None,
+        _ => x.windows(2).position(|w| w[1] == w[0]),
+    };
+    match (policy, duplicate) {
+        (DuplicateXPolicy::Error, Some(index)) => {
+            return Err(IntegrateError::DuplicateAbscissa { index });
+        }
+        #[cfg(feature = "log")]
+        (DuplicateXPolicy::Warn, Some(index)) => {
+            log::warn!("duplicate abscissa at index {index}, its segment contributes nothing");
+        }
+        _ => {}
+    }
+    try_trapezoid(y, x)
+}
+
+pub(crate) fn try_trapezoid<T: TrapezSample>(y: &[T], x: &[T]) -> Result<T, IntegrateError> {
+    try_trapezoid_with_tol(y, x, T::TOLERANCE)
+}
+
+fn try_trapezoid_with_tol<T: TrapezSample>(
+    y: &[T],
+    x: &[T],
+    rel_tol: T,
+) -> Result<T, IntegrateError> {
+    validate(y, x)?;
+    let grid = classify_iter(x.iter().copied(), rel_tol)?;
+    let integral = integrate_signed(y, x, grid.step);
+    if grid.descending {
+        Ok(-integral)
+    } else {
+        Ok(integral)
+    }
+}
+
+pub(crate) fn trapezoid_signed<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+    match validate(y, x) {
+        Ok(_) => integrate_signed(y, x, uniform_spacing(x)),
+        Err(_) => T::nan(),
+    }
+}
+
+/// Explains a non-finite `value`: an infinite sample in `y` is a divergence and gives
+/// [IntegrateError::Divergent], a non-finite result from finite `y` and `x` is
+/// [IntegrateError::Overflow]. NaN samples are missing data rather than a divergence,
+/// they pass through as a NaN result for the caller to handle, e.g. with
+/// [trapezoid_nan_ignore_f64](crate::trapezoid_nan_ignore_f64). Infinite abscissas pass through too.
+///
+/// The inputs are only scanned when the result is already non-finite, so the
+/// successful path costs a single comparison.
+pub(crate) fn detect_overflow<T: TrapezSample>(
+    value: T,
+    y: &[T],
+    x: &[T],
+) -> Result<T, IntegrateError> {
+    if value.is_finite() {
+        return Ok(value);
+    }
+    if let Some(index) = y.iter().position(|v| v.is_infinite()) {
+        return Err(IntegrateError::Divergent { index });
+    }
+    if y.iter().chain(x.iter()).any(|v| !v.is_finite()) {
+        Ok(value)
+    } else {
+        Err(IntegrateError::Overflow)
+    }
+}
+
+#[inline]
+pub(crate) fn validate<T>(y: &[T], x: &[T]) -> Result<(), IntegrateError> {
+    if x.len() != y.len() {
+        return Err(IntegrateError::LengthMismatch {
+            y: y.len(),
+            x: x.len(),
+        });
+    }
+    if y.len() < 2 {
+        return Err(IntegrateError::TooFewPoints);
+    }
+    Ok(())
+}
+
+/// Shape of a monotonic grid of abscissas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Grid<T> {
+    /// Common spacing if the grid is uniform.
+    pub(crate) step: Option<T>,
+    /// Whether the abscissas are descending.
+    pub(crate) descending: bool,
+}
+
+/// Classifies `x` in a single pass: uniform spacing detection (see [uniform_spacing])
+/// and the monotonicity check share the same loop over the intervals.
+///
+/// Repeated abscissas are accepted in either direction, their zero-width segments
+/// contribute nothing to the integral.
+///
+/// # Errors
+/// [IntegrateError::NonMonotonic] with the index of the first interval running against
+/// the direction set by the first non-zero interval.
+pub(crate) fn classify<T: TrapezSample>(x: &[T]) -> Result<Grid<T>, IntegrateError> {
+    classify_iter(x.iter().copied(), T::TOLERANCE)
+}
+
+/// [classify] over abscissas produced by an iterator yielding at least two values,
+/// with a custom uniform spacing tolerance (see [SpacingCheck]).
+pub(crate) fn classify_iter<T: TrapezSample>(
+    mut x: impl Iterator<Item = T>,
+    rel_tol: T,
+) -> Result<Grid<T>, IntegrateError> {
+    let (Some(x0), Some(x1)) = (x.next(), x.next()) else {
+        return Err(IntegrateError::TooFewPoints);
+    };
+    // seeded by the first non-zero interval, a zero `h0` would make the tolerance absolute
+    let mut check: Option<SpacingCheck<T>> = None;
+    let mut uniform = true;
+    let mut descending = None;
+    let mut prev = x0;
+    for (i, next) in core::iter::once(x1).chain(x).enumerate() {
+        let dx = next - prev;
+        prev = next;
+        if dx == T::zero() {
+            // a zero-width segment is skipped by the integration but breaks uniform spacing
+            uniform = false;
+            continue;
+        }
+        match check {
+            None => check = Some(SpacingCheck::new(dx, rel_tol)),
+            Some(c) => uniform &= c.matches(dx),
+        }
+        let down = dx < T::zero();
+        match descending {
+            None => descending = Some(down),
+            Some(d) if d != down => return Err(IntegrateError::NonMonotonic { index: i }),
+            _ => {}
+        }
+    }
+    Ok(Grid {
+        step: check.filter(|_| uniform).map(|c| c.h0),
+        descending: descending.unwrap_or(false),
+    })
+}
+
+/// Trapezoid rule with the sign of every segment following `x[i + 1] - x[i]`,
+/// `y` and `x` must be validated, `step` is the uniform spacing of `x` if any.
+pub(crate) fn integrate_signed<T: TrapezSample>(y: &[T], x: &[T], step: Option<T>) -> T {
+    let n = y.len();
+    if let Some(h0) = step {
+        // Use the optimized uniform-spacing trapezoid rule:
+        // integral = h * ( 0.5*y0 + sum(y[1..n-1]) + 0.5*yn )
+        let interior_sum = sum(&y[1..(n - 1)]);
+        h0 * fmla(y[0] + y[n - 1], T::HALF, interior_sum)
+    } else {
+        // General (non-uniform) trapezoid rule
+        segment_sum(y, x)
+    }
+}
+
+/// Checks whether the abscissas are uniformly spaced.
+///
+/// Every interval is compared against the first one, the spacing is uniform if no
+/// interval differs from it by more than `rel_tol * max(|x[1] - x[0]|, 1)`.
+/// The bound is inclusive, and for spacings below one it acts as an absolute tolerance.
+/// A repeated abscissa, a zero-width interval, always makes the grid non-uniform.
+/// This is the same test [trapezoid_f64] uses with `rel_tol = 1e-12`.
+///
+/// Returns `false` if `x` has fewer than two elements.
+pub fn is_uniformly_spaced_f64(x: &[f64], rel_tol: f64) -> bool {
+    x.len() >= 2 && uniform_spacing_with_tol(x, rel_tol).is_some()
+}
+
+/// Checks whether the abscissas are uniformly spaced.
+///
+/// Every interval is compared against the first one, the spacing is uniform if no
+/// interval differs from it by more than `rel_tol * max(|x[1] - x[0]|, 1)`.
+/// The bound is inclusive, and for spacings below one it acts as an absolute tolerance.
+/// A repeated abscissa, a zero-width interval, always makes the grid non-uniform.
+/// This is the same test [trapezoid_f32] uses with `rel_tol = 1e-6`.
+///
+/// Returns `false` if `x` has fewer than two elements.
+pub fn is_uniformly_spaced_f32(x: &[f32], rel_tol: f32) -> bool {
+    x.len() >= 2 && uniform_spacing_with_tol(x, rel_tol).is_some()
+}
+
+/// Describes the sampling domain of `x` without integrating.
+///
+/// Together with [is_uniformly_spaced_f64] this characterizes a grid before choosing
+/// an integration routine. The extremes are taken over all samples, so they are
+/// meaningful for unsorted input too, NaN samples are ignored.
+///
+/// # Returns
+/// `(min, max, number of segments)`, or `None` if `x` has fewer than two elements.
+pub fn integration_domain_f64(x: &[f64]) -> Option<(f64, f64, usize)> {
+    integration_domain(x)
+}
+
+/// Describes the sampling domain of `x` without integrating.
+///
+/// Together with [is_uniformly_spaced_f32] this characterizes a grid before choosing
+/// an integration routine. The extremes are taken over all samples, so they are
+/// meaningful for unsorted input too, NaN samples are ignored.
+///
+/// # Returns
+/// `(min, max, number of segments)`, or `None` if `x` has fewer than two elements.
+pub fn integration_domain_f32(x: &[f32]) -> Option<(f32, f32, usize)> {
+    integration_domain(x)
+}
+
+fn integration_domain<T: TrapezSample>(x: &[T]) -> Option<(T, T, usize)> {
+    if x.len() < 2 {
+        return None;
+    }
+    let (min, max) = x
+        .iter()
+        .fold((T::infinity(), T::neg_infinity()), |(lo, hi), &v| {
+            (lo.min(v), hi.max(v))
+        });
+    Some((min, max, x.len() - 1))
+}
+
+/// Returns the common spacing of `x` if all intervals match the first one
+/// within [TrapezSample::TOLERANCE] and none has zero width, `x` must have at least
+/// two elements.
+pub(crate) fn uniform_spacing<T: TrapezSample>(x: &[T]) -> Option<T> {
+    uniform_spacing_with_tol(x, T::TOLERANCE)
+}
+
+pub(crate) fn uniform_spacing_with_tol<T: TrapezSample>(x: &[T], rel_tol: T) -> Option<T> {
+    let h0 = x[1] - x[0];
+    // a zero first interval would make the tolerance absolute, and a grid with a
+    // repeated abscissa among non-zero intervals is never uniform
+    if h0 == T::zero() {
+        return None;
+    }
+    let check = SpacingCheck::new(h0, rel_tol);
+
+    let q = &x[1..];
+    for x in q.windows(2) {
+        let dx = x[1] - x[0];
+        if dx == T::zero() || !check.matches(dx) {
+            return None;
+        }
+    }
+    Some(h0)
+}
+
+/// Uniform spacing test against the first interval `h0`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SpacingCheck<T> {
+    h0: T,
+    tol: T,
+}
+
+impl<T: TrapezSample> SpacingCheck<T> {
+    #[inline]
+    pub(crate) fn new(h0: T, rel_tol: T) -> Self {
+        // tolerance scaled to magnitude of h0 (and at least a tiny absolute tol)
+        let tol = (h0.abs().max(T::one())) * rel_tol;
+        Self { h0, tol }
+    }
+
+    #[inline]
+    pub(crate) fn matches(&self, dx: T) -> bool {
+        (dx - self.h0).abs() <= self.tol
+    }
+}
+
+/// Adds the area of the trapezoid spanned by two consecutive samples to `acc`.
+/// `y` and `x` are windows of length two.
+#[inline(always)]
+pub(crate) fn add_segment<T: TrapezSample>(acc: T, y: &[T], x: &[T]) -> T {
+    let dx = x[1] - x[0];
+    fmla(dx * T::HALF, y[0] + y[1], acc)
+}
+
+/// Trapezoidal integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+pub fn trapezoid_even_f32(y: &[f32], dx: f32) -> f32 {
+    trapezoid_even(y, dx)
+}
+
+/// Trapezoidal integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+pub fn trapezoid_even_f64(y: &[f64], dx: f64) -> f64 {
+    trapezoid_even(y, dx)
+}
+
+/// Checked version of [trapezoid_even_f32].
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::NonFiniteSpacing] if `dx` is NaN or infinite.
+/// * [IntegrateError::NonPositiveSpacing] if `dx <= 0`.
+/// * [IntegrateError::Overflow] if all samples are finite but the accumulated sum is not.
+/// * [IntegrateError::Divergent] if a sample is infinite, NaN samples give a NaN result.
+pub fn try_trapezoid_even_f32(y: &[f32], dx: f32) -> Result<f32, IntegrateError> {
+    try_trapezoid_even(y, dx).and_then(|v| detect_overflow(v, y, &[dx]))
+}
+
+/// [try_trapezoid_even_f32] that can also reject non-finite samples.
+///
+/// With `scan_y` the samples are checked before integrating, which costs one extra
+/// pass over `y` but points at the offending sample instead of returning NaN or
+/// infinity.
+///
+/// # Errors
+/// Same as [try_trapezoid_even_f32], and with `scan_y` set
+/// [IntegrateError::NonFiniteSample] for the first NaN or infinite sample.
+pub fn try_trapezoid_even_finite_f32(
+    y: &[f32],
+    dx: f32,
+    scan_y: bool,
+) -> Result<f32, IntegrateError> {
+    try_trapezoid_even_finite(y, dx, scan_y).and_then(|v| detect_overflow(v, y, &[dx]))
+}
+
+/// Checked version of [trapezoid_even_f64].
+///
+/// # Errors
+/// * [IntegrateError::TooFewPoints] if `y` has fewer than two samples.
+/// * [IntegrateError::NonFiniteSpacing] if `dx` is NaN or infinite.
+/// * [IntegrateError::NonPositiveSpacing] if `dx <= 0`.
+/// * [IntegrateError::Overflow] if all samples are finite but the accumulated sum is not.
+/// * [IntegrateError::Divergent] if a sample is infinite, NaN samples give a NaN result.
+pub fn try_trapezoid_even_f64(y: &[f64], dx: f64) -> Result<f64, IntegrateError> {
+    try_trapezoid_even(y, dx).and_then(|v| detect_overflow(v, y, &[dx]))
+}
+
+/// [try_trapezoid_even_f64] that can also reject non-finite samples.
+///
+/// With `scan_y` the samples are checked before integrating, which costs one extra
+/// pass over `y` but points at the offending sample instead of returning NaN or
+/// infinity.
+///
+/// # Errors
+/// Same as [try_trapezoid_even_f64], and with `scan_y` set
+/// [IntegrateError::NonFiniteSample] for the first NaN or infinite sample.
+pub fn try_trapezoid_even_finite_f64(
+    y: &[f64],
+    dx: f64,
+    scan_y: bool,
+) -> Result<f64, IntegrateError> {
+    try_trapezoid_even_finite(y, dx, scan_y).and_then(|v| detect_overflow(v, y, &[dx]))
+}
+
+/// Trapezoidal integration for evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Generic version of [trapezoid_even_f32] and [trapezoid_even_f64].
+pub fn trapezoid_even<T: TrapezSample>(y: &[T], dx: T) -> T {
+    try_trapezoid_even(y, dx).unwrap_or(T::nan())
+}
+
+/// Trapezoidal integration for a fixed-size array of evenly spaced samples.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// Same as [trapezoid_even] with the length known at compile time, which lets the
+/// compiler unroll the interior sum for small arrays.
+/// `N` must be at least 2, otherwise the result is NaN just like for a short slice.
+pub fn trapezoid_even_array<T: TrapezSample, const N: usize>(y: &[T; N], dx: T) -> T {
+    trapezoid_even(y.as_slice(), dx)
+}
+
+/// Trapezoidal integration for evenly spaced samples, usable in `const` items.
+/// `y` = function values
+/// `dx` = spacing between x-values
+///
+/// The interior is summed left to right, so for long inputs the result may differ in
+/// the last bits from [trapezoid_even_f64], which sums pairwise and may use FMA.
+///
+/// ```
+/// use trapez_integrate::trapezoid_even_const;
+/// const AREA: f64 = trapezoid_even_const(&[0., 1., 4., 9.], 0.5);
+/// assert_eq!(AREA, 4.75);
+/// ```
+///
+/// Returns NaN if `y` has fewer than two samples or `dx` is not finite and positive.
+pub const fn trapezoid_even_const(y: &[f64], dx: f64) -> f64 {
+    let n = y.len();
+    if n < 2 || !dx.is_finite() || dx <= 0. {
+        return f64::NAN;
+    }
+    let mut interior = 0.;
+    let mut i = 1;
+    while i < n - 1 {
+        interior += y[i];
+        i += 1;
+    }
+    dx * ((y[0] + y[n - 1]) * 0.5 + interior)
+}
+
+/// [trapezoid_even_f64] without the input checks, for hot loops over validated data.
+///
+/// The sample count and the spacing are asserted in debug builds only.
+///
+/// # Safety
+/// `y` must hold at least two samples, shorter slices are read out of bounds.
+/// `dx` should be positive, otherwise the result is meaningless but still defined.
+pub unsafe fn trapezoid_even_unchecked_f64(y: &[f64], dx: f64) -> f64 {
+    // Safety: forwarded from the caller.
+    unsafe { trapezoid_even_unchecked(y, dx) }
+}
+
+/// [trapezoid_even_f32] without the input checks, for hot loops over validated data.
+///
+/// The sample count and the spacing are asserted in debug builds only.
+///
+/// # Safety
+/// `y` must hold at least two samples, shorter slices are read out of bounds.
+/// `dx` should be positive, otherwise the result is meaningless but still defined.
+pub unsafe fn trapezoid_even_unchecked_f32(y: &[f32], dx: f32) -> f32 {
+    // Safety: forwarded from the caller.
+    unsafe { trapezoid_even_unchecked(y, dx) }
+}
+
+#[inline]
+unsafe fn trapezoid_even_unchecked<T: TrapezSample>(y: &[T], dx: T) -> T {
+    debug_assert!(y.len() >= 2, "at least two samples are required");
+    debug_assert!(dx > T::zero(), "spacing must be positive");
+    let n = y.len();
+    // Safety: the caller guarantees n >= 2.
+    let (first, last, interior) = unsafe {
+        (
+            *y.get_unchecked(0),
+            *y.get_unchecked(n - 1),
+            y.get_unchecked(1..n - 1),
+        )
+    };
+    dx * fmla(T::HALF, first + last, sum(interior))
+}
+
+/// Trapezoidal integration of one period of a periodic signal.
+/// `y` = function values over one period, without repeating the first sample at the end
+/// `dx` = spacing between x-values
+///
+/// The samples wrap around, so the closing segment runs from `y[n - 1]` back to `y[0]`
+/// and the interval width is `n * dx`. Every sample then has weight `dx`, unlike
+/// [trapezoid_even_f64] where the two endpoints get `dx / 2` over `(n - 1) * dx`.
+/// For smooth periodic functions this converges exponentially fast.
+///
+/// Returns NaN if `y` is empty or `dx <= 0`.
+pub fn trapezoid_periodic_even_f64(y: &[f64], dx: f64) -> f64 {
+    trapezoid_periodic_even(y, dx)
+}
+
+/// Trapezoidal integration of one period of a periodic signal.
+/// `y` = function values over one period, without repeating the first sample at the end
+/// `dx` = spacing between x-values
+///
+/// The samples wrap around, so the closing segment runs from `y[n - 1]` back to `y[0]`
+/// and the interval width is `n * dx`. Every sample then has weight `dx`, unlike
+/// [trapezoid_even_f32] where the two endpoints get `dx / 2` over `(n - 1) * dx`.
+/// For smooth periodic functions this converges exponentially fast.
+///
+/// Returns NaN if `y` is empty or `dx <= 0`.
+pub fn trapezoid_periodic_even_f32(y: &[f32], dx: f32) -> f32 {
+    trapezoid_periodic_even(y, dx)
+}
+
+fn trapezoid_periodic_even<T: TrapezSample>(y: &[T], dx: T) -> T {
+    if y.is_empty() || dx <= T::zero() {
+        return T::nan();
+    }
+    dx * sum(y)
+}
+
+/// Trapezoidal integration over precomputed interval widths.
+/// `y` = function values
+/// `dx` = interval widths, `dx[i] = x[i + 1] - x[i]`
+///
+/// Lets a caller that integrates many series on one non-uniform grid compute the
+/// differences once and reuse the buffer. For `dx` taken from `x` the result is
+/// bitwise the one [trapezoid_signed_f64] gives on a non-uniform `x`.
+/// The widths are not checked, every segment counts with the sign of its `dx[i]`.
+///
+/// Returns NaN if `y` has fewer than two samples or `dx.len() != y.len() - 1`.
+pub fn trapezoid_with_dx_f64(y: &[f64], dx: &[f64]) -> f64 {
+    trapezoid_with_dx(y, dx)
+}
+
+/// Trapezoidal integration over precomputed interval widths.
+/// `y` = function values
+/// `dx` = interval widths, `dx[i] = x[i + 1] - x[i]`
+///
+/// Lets a caller that integrates many series on one non-uniform grid compute the
+/// differences once and reuse the buffer. For `dx` taken from `x` the result is
+/// bitwise the one [trapezoid_signed_f32] gives on a non-uniform `x`.
+/// The widths are not checked, every segment counts with the sign of its `dx[i]`.
+///
+/// Returns NaN if `y` has fewer than two samples or `dx.len() != y.len() - 1`.
+pub fn trapezoid_with_dx_f32(y: &[f32], dx: &[f32]) -> f32 {
+    trapezoid_with_dx(y, dx)
+}
+
+fn trapezoid_with_dx<T: TrapezSample>(y: &[T], dx: &[T]) -> T {
+    if y.len() < 2 || dx.len() != y.len() - 1 {
+        return T::nan();
+    }
+    segment_sum_dx(y, dx)
+}
+
+fn try_trapezoid_even_finite<T: TrapezSample>(
+    y: &[T],
+    dx: T,
+    scan_y: bool,
+) -> Result<T, IntegrateError> {
+    if scan_y && let Some(index) = y.iter().position(|v| !v.is_finite()) {
+        return Err(IntegrateError::NonFiniteSample { index });
+    }
+    try_trapezoid_even(y, dx)
+}
+
+pub(crate) fn try_trapezoid_even<T: TrapezSample>(y: &[T], dx: T) -> Result<T, IntegrateError> {
+    let n = y.len();
+    if n < 2 {
+        return Err(IntegrateError::TooFewPoints);
+    }
+    if !dx.is_finite() {
+        return Err(IntegrateError::NonFiniteSpacing);
+    }
+    if dx <= T::zero() {
+        return Err(IntegrateError::NonPositiveSpacing);
+    }
+
+    // sum of interior terms
+    let interior_sum = sum(&y[1..n - 1]);
+
+    Ok(dx * fmla(T::HALF, y[0] + y[n - 1], interior_sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoid() {
+        let result = trapezoid_f64(&[5., 6., 1., 4., 6., 2.], &[1., 2., 4., 6., 7., 9.]);
+        assert_eq!(result, 30.5);
+    }
+
+    #[test]
+    fn test_trapezoid_f32() {
+        let y: Vec<f32> = vec![5., 6., 1., 4., 6., 2.];
+        // non-uniform spacing
+        let x: Vec<f32> = vec![1., 2., 4., 6., 7., 9.];
+        let result: f32 = trapezoid_f32(&y, &x);
+        assert_eq!(result, 30.5f32);
+        // uniform spacing
+        let x: Vec<f32> = vec![0., 0.5, 1., 1.5, 2., 2.5];
+        let result: f32 = trapezoid_f32(&y, &x);
+        assert!((result - 10.25f32).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_trapezoid_even() {
+        let result = trapezoid_even(&[5., 6., 1., 4., 6., 2.], 0.003);
+        assert_eq!(result, 0.0615);
+        let array: [f64; 6] = [5., 6., 1., 4., 6., 2.];
+        assert_eq!(trapezoid_even_array(&array, 0.003), 0.0615);
+        assert!(trapezoid_even_array(&[1f32], 1.).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_generic() {
+        fn total<T: TrapezSample>(y: &[T], x: &[T]) -> T {
+            trapezoid(y, x)
+        }
+        assert_eq!(
+            total(&[5f32, 6., 1., 4., 6., 2.], &[1., 2., 4., 6., 7., 9.]),
+            30.5f32
+        );
+        assert_eq!(
+            total(&[5f64, 6., 1., 4., 6., 2.], &[1., 2., 4., 6., 7., 9.]),
+            30.5f64
+        );
+    }
+
+    #[test]
+    fn test_try_trapezoid() {
+        assert_eq!(
+            try_trapezoid_f64(&[5., 6., 1., 4., 6., 2.], &[1., 2., 4., 6., 7., 9.]),
+            Ok(30.5)
+        );
+        assert_eq!(
+            try_trapezoid_f64(&[1.], &[1.]),
+            Err(IntegrateError::TooFewPoints)
+        );
+        assert_eq!(
+            try_trapezoid_f32(&[1., 2., 3.], &[1., 2.]),
+            Err(IntegrateError::LengthMismatch { y: 3, x: 2 })
+        );
+        assert_eq!(
+            try_trapezoid_even_f64(&[1., 2., 3.], 0.),
+            Err(IntegrateError::NonPositiveSpacing)
+        );
+        assert!(trapezoid_f64(&[1., 2., 3.], &[1., 2.]).is_nan());
+    }
+
+    #[test]
+    fn test_try_trapezoid_monotonic() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        assert_eq!(try_trapezoid_f64(&y, &[1., 2., 4., 6., 7., 9.]), Ok(30.5));
+        // repeated abscissa forms an empty segment
+        assert_eq!(
+            try_trapezoid_f64(&y, &[1., 2., 2., 6., 7., 9.]),
+            Ok(5.5 + 10. + 5. + 8.)
+        );
+        assert_eq!(
+            try_trapezoid_f64(&y, &[1., 2., 4., 3., 7., 9.]),
+            Err(IntegrateError::NonMonotonic { index: 2 })
+        );
+        assert_eq!(
+            try_trapezoid_f32(&[1., 1., 1.], &[0., 1., 0.]),
+            Err(IntegrateError::NonMonotonic { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_is_uniformly_spaced() {
+        let x = [0., 1., 2.5];
+        // |1.5 - 1| == 0.5 sits exactly on the bound, which is inclusive
+        assert!(is_uniformly_spaced_f64(&x, 0.5));
+        assert!(!is_uniformly_spaced_f64(&x, 0.25));
+        // below unit spacing the tolerance is absolute
+        assert!(is_uniformly_spaced_f32(&[0., 0.25, 0.5, 1.], 0.25));
+        assert!(!is_uniformly_spaced_f32(&[0., 0.25, 0.5, 1.], 0.125));
+        assert!(is_uniformly_spaced_f64(&[1., 2., 3., 4.], 0.));
+        assert!(!is_uniformly_spaced_f64(&[1.], 1.));
+    }
+
+    #[test]
+    fn test_trapezoid_or_zero() {
+        assert!(trapezoid_f64(&[], &[]).is_nan());
+        assert!(trapezoid_f64(&[2.], &[1.]).is_nan());
+        assert_eq!(trapezoid_or_zero_f64(&[], &[]), 0.);
+        assert_eq!(trapezoid_or_zero_f64(&[2.], &[1.]), 0.);
+        assert_eq!(trapezoid_or_zero_f32(&[1., 3.], &[0., 2.]), 4.);
+        assert!(trapezoid_or_zero_f64(&[2.], &[1., 2.]).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_with_tol() {
+        let x: Vec<f64> = (0..9)
+            .map(|i| i as f64 * 0.1 + (i % 2) as f64 * 1e-10)
+            .collect();
+        let y: Vec<f64> = x.iter().map(|&v| v * v).collect();
+        let even = trapezoid_even_f64(&y, x[1] - x[0]);
+        let exact = trapezoid_f64(&y, &x);
+        assert_ne!(even, exact);
+        assert_eq!(trapezoid_with_tol_f64(&y, &x, 1e-6), even);
+        assert_eq!(trapezoid_with_tol_f64(&y, &x, 1e-12), exact);
+        assert!(trapezoid_with_tol_f32(&[1.], &[1.], 1e-3).is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "deterministic")]
+    fn test_deterministic_bits() {
+        // only correctly rounded operations, so every target has to agree on the bits
+        let y: [f64; 5000] = core::array::from_fn(|i| 1. / (i + 1) as f64);
+        let x: [f64; 5000] = core::array::from_fn(|i| i as f64 + (i % 3) as f64 * 0.25);
+        assert_eq!(trapezoid_even_f64(&y, 0.1).to_bits(), 0x3feb808a2be677a0);
+        assert_eq!(trapezoid_f64(&y, &x).to_bits(), 0x402199738b51e62e);
+    }
+
+    #[test]
+    fn test_trapezoid_f32_acc64() {
+        let x: Vec<f32> = (0..1_000_000).map(|i| i as f32 * 1e-3).collect();
+        let y: Vec<f32> = x.iter().map(|&v| 0.1 + (v * 0.37).sin() * 1e-4).collect();
+        let reference = trapezoid_f64(
+            &y.iter().map(|&v| v as f64).collect::<Vec<_>>(),
+            &x.iter().map(|&v| v as f64).collect::<Vec<_>>(),
+        );
+        let naive = y.windows(2).zip(x.windows(2)).fold(0f32, |acc, (y, x)| {
+            acc + 0.5 * (y[0] + y[1]) * (x[1] - x[0])
+        });
+        let wide = trapezoid_f32_acc64(&y, &x);
+        assert!(
+            (wide - reference).abs() < 1e-9 * reference,
+            "{wide} vs {reference}"
+        );
+        assert!((naive as f64 - reference).abs() > 1e-4 * reference);
+        assert!(trapezoid_f32_acc64(&[1.], &[1.]).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_even_unchecked() {
+        let y: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.01).cos()).collect();
+        let unchecked = unsafe { trapezoid_even_unchecked_f64(&y, 0.01) };
+        assert_eq!(unchecked, trapezoid_even_f64(&y, 0.01));
+        let unchecked = unsafe { trapezoid_even_unchecked_f32(&[5., 6., 1., 4., 6., 2.], 0.5) };
+        assert_eq!(unchecked, 10.25);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "at least two samples")]
+    fn test_trapezoid_even_unchecked_misuse() {
+        unsafe { trapezoid_even_unchecked_f64(&[1.], 1.) };
+    }
+
+    #[test]
+    fn test_trapezoid_periodic_even() {
+        let n = 64;
+        let dx = core::f64::consts::TAU / n as f64;
+        let sin: Vec<f64> = (0..n).map(|i| (i as f64 * dx).sin()).collect();
+        assert!(trapezoid_periodic_even_f64(&sin, dx).abs() < 1e-14);
+        // sin^2 averages to 1/2 over the period
+        let sq: Vec<f64> = sin.iter().map(|v| v * v).collect();
+        assert!((trapezoid_periodic_even_f64(&sq, dx) - core::f64::consts::PI).abs() < 1e-14);
+        assert_eq!(trapezoid_periodic_even_f32(&[2.; 4], 0.5), 4.);
+        assert!(trapezoid_periodic_even_f32(&[], 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_segment_areas() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let areas: Vec<f64> = trapezoid_segment_areas_f64(&y, &x).collect();
+        assert_eq!(areas, vec![5.5, 7., 5., 5., 8.]);
+        assert_eq!(areas.iter().sum::<f64>(), trapezoid_f64(&y, &x));
+        assert_eq!(trapezoid_segment_areas_f32(&[1., 2.], &[0.]).count(), 0);
+    }
+
+    #[test]
+    fn test_trapezoid_mixed() {
+        let x: Vec<f64> = (0..500)
+            .map(|i| 1.7e9 + i as f64 * 0.001 + (i % 7) as f64 * 1e-4)
+            .collect();
+        let y: Vec<f32> = (0..500).map(|i| (i as f32 * 0.1).sin()).collect();
+        let widened: Vec<f64> = y.iter().map(|&v| v as f64).collect();
+        assert_eq!(trapezoid_mixed_f64(&y, &x), trapezoid_f64(&widened, &x));
+        assert!(trapezoid_mixed_f64(&y[1..], &x).is_nan());
+    }
+
+    #[test]
+    fn test_try_trapezoid_even_non_finite() {
+        let y = [1., 2., f64::NAN, 4.];
+        assert_eq!(
+            try_trapezoid_even_f64(&y, f64::NAN),
+            Err(IntegrateError::NonFiniteSpacing)
+        );
+        assert_eq!(
+            try_trapezoid_even_f32(&[1., 2.], f32::INFINITY),
+            Err(IntegrateError::NonFiniteSpacing)
+        );
+        assert!(
+            try_trapezoid_even_finite_f64(&y, 0.5, false)
+                .unwrap()
+                .is_nan()
+        );
+        assert_eq!(
+            try_trapezoid_even_finite_f64(&y, 0.5, true),
+            Err(IntegrateError::NonFiniteSample { index: 2 })
+        );
+        assert_eq!(try_trapezoid_even_finite_f32(&[1., 3.], 0.5, true), Ok(1.));
+    }
+
+    #[test]
+    fn test_try_trapezoid_overflow() {
+        let y = [f32::MAX / 2.; 64];
+        assert_eq!(
+            try_trapezoid_even_f32(&y, 1e-10),
+            Err(IntegrateError::Overflow)
+        );
+        let x: [f32; 64] = core::array::from_fn(|i| i as f32 + (i % 3) as f32 * 0.25);
+        assert!(!is_uniformly_spaced_f32(&x, f32::TOLERANCE));
+        assert_eq!(try_trapezoid_f32(&y, &x), Err(IntegrateError::Overflow));
+        // unchecked variants keep the IEEE result
+        assert_eq!(trapezoid_even_f32(&y, 1e-10), f32::INFINITY);
+        assert_eq!(trapezoid_f32(&y, &x), f32::INFINITY);
+        // the integral fits while the segment sums do not,
+        // the widened accumulator keeps them in range
+        let y = [f32::MAX / 64.; 64];
+        assert_eq!(try_trapezoid_f32(&y, &x), Err(IntegrateError::Overflow));
+        let wide = try_trapezoid_wide_f32(&y, &x).unwrap();
+        assert!((wide / (f32::MAX / 64. * 63.) - 1.).abs() < 1e-6);
+        let mut y = y;
+        y[3] = f32::INFINITY;
+        assert_eq!(
+            try_trapezoid_even_f32(&y, 1e-10),
+            Err(IntegrateError::Divergent { index: 3 })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_policy() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 4., 7., 9.];
+        let allowed = try_trapezoid_with_duplicates_f64(&y, &x, DuplicateXPolicy::Allow);
+        assert_eq!(allowed, try_trapezoid_f64(&y, &x));
+        assert_eq!(
+            try_trapezoid_with_duplicates_f64(&y, &x, DuplicateXPolicy::Warn),
+            allowed
+        );
+        assert_eq!(
+            try_trapezoid_with_duplicates_f64(&y, &x, DuplicateXPolicy::Error),
+            Err(IntegrateError::DuplicateAbscissa { index: 2 })
+        );
+        let x = [1., 2., 4., 6., 7., 9.];
+        assert_eq!(
+            try_trapezoid_with_duplicates_f64(&y, &x, DuplicateXPolicy::Error),
+            Ok(30.5)
+        );
+    }
+
+    #[test]
+    fn test_integration_domain() {
+        let x = [1., 2., 4., 6., 7., 9.];
+        assert_eq!(integration_domain_f64(&x), Some((1.0, 9.0, 5)));
+        assert_eq!(integration_domain_f32(&[3., 1., 2.]), Some((1.0, 3.0, 2)));
+        assert_eq!(integration_domain_f64(&[1.]), None);
+    }
+
+    #[test]
+    fn test_trapezoid_direction() {
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        // descending sweep of the same data
+        let y_rev = [2., 6., 4., 1., 6., 5.];
+        let x_rev = [9., 7., 6., 4., 2., 1.];
+        assert_eq!(trapezoid_f64(&y, &x), 30.5);
+        assert_eq!(trapezoid_f64(&y_rev, &x_rev), 30.5);
+        assert_eq!(trapezoid_signed_f64(&y, &x), 30.5);
+        assert_eq!(trapezoid_signed_f64(&y_rev, &x_rev), -30.5);
+        // uniform descending grid
+        assert_eq!(trapezoid_f32(&[1., 2., 3.], &[2., 1., 0.]), 4.);
+        // scrambled
+        let x_mixed = [1., 4., 2., 6., 7., 9.];
+        assert!(trapezoid_f64(&y, &x_mixed).is_nan());
+        assert_eq!(
+            try_trapezoid_f64(&y, &x_mixed),
+            Err(IntegrateError::NonMonotonic { index: 1 })
+        );
+        assert!(!trapezoid_signed_f64(&y, &x_mixed).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_int() {
+        let x = [0., 0.5, 2., 2.25, 4.];
+        let counts: [u32; 5] = [3, 0, 7, 4_000_000_000, 1];
+        let cast = counts.map(|c| c as f64);
+        assert_eq!(trapezoid_int_f64(&counts, &x), trapezoid_f64(&cast, &x));
+        let signed: [i16; 4] = [-2, 5, 5, 1];
+        assert_eq!(trapezoid_int_f64(&signed, &[3., 2., 1., 0.]), 9.5);
+        assert!(trapezoid_int_f64(&signed, &x).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_even_const() {
+        const RESULT: f64 = trapezoid_even_const(&[1., 2., 3., 4.], 0.1);
+        assert_eq!(RESULT, trapezoid_even_f64(&[1., 2., 3., 4.], 0.1));
+        const SHORT: f64 = trapezoid_even_const(&[1.], 0.1);
+        assert!(SHORT.is_nan());
+        assert!(trapezoid_even_const(&[1., 2.], f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_single_pass() {
+        let x: Vec<f64> = (0..5000).map(|i| (i as f64 * 1e-3).powi(2)).collect();
+        let y: Vec<f64> = x.iter().map(|&x| (x * 7.).sin()).collect();
+        let two_pass = trapezoid_f64(&y, &x);
+        assert_eq!(
+            trapezoid_single_pass_f64(&y, &x).to_bits(),
+            two_pass.to_bits()
+        );
+        let rev_x: Vec<f64> = x.iter().rev().copied().collect();
+        let rev_y: Vec<f64> = y.iter().rev().copied().collect();
+        assert_eq!(
+            trapezoid_single_pass_f64(&rev_y, &rev_x),
+            trapezoid_f64(&rev_y, &rev_x)
+        );
+        assert!((trapezoid_single_pass_f64(&rev_y, &rev_x) - two_pass).abs() < 1e-15);
+
+        let even = [0., 0.5, 1., 1.5, 2.];
+        let y = [5., 6., 1., 4., 6.];
+        assert!((trapezoid_single_pass_f64(&y, &even) - trapezoid_f64(&y, &even)).abs() < 1e-15);
+        assert!(trapezoid_single_pass_f64(&y, &[0., 1., 2., 1.5, 3.]).is_nan());
+        assert!(trapezoid_single_pass_f32(&[1.], &[0.]).is_nan());
+    }
+
+    #[test]
+    fn test_repeated_first_abscissa() {
+        let x = [0., 0., 1., 2., 3.];
+        let y = [7., 1., 2., 3., 4.];
+        assert_eq!(classify(&x).unwrap().step, None);
+        assert_eq!(uniform_spacing(&x), None);
+        assert!(!is_uniformly_spaced_f64(&x, 1e-12));
+        assert_eq!(trapezoid_f64(&y, &x), 7.5);
+        assert_eq!(trapezoid_signed_f64(&y, &x), 7.5);
+        // spacings below the tolerance must not be taken as uniform with a zero step
+        let tiny = [0., 0., 1e-13, 2e-13, 3e-13];
+        let ones = [1.; 5];
+        assert!((trapezoid_f64(&ones, &tiny) - 3e-13).abs() < 1e-27);
+        assert!((trapezoid_signed_f64(&ones, &tiny) - 3e-13).abs() < 1e-27);
+        assert_eq!(trapezoid_f64(&ones, &[2.; 5]), 0.);
+    }
+
+    #[test]
+    fn test_divergent_samples() {
+        let x = [0., 1., 2., 3.];
+        let mut y = [1., 2., 3., 4.];
+        y[2] = f64::INFINITY;
+        assert_eq!(
+            try_trapezoid_f64(&y, &x),
+            Err(IntegrateError::Divergent { index: 2 })
+        );
+        y[1] = f64::NEG_INFINITY;
+        assert_eq!(
+            try_trapezoid_f64(&y, &x),
+            Err(IntegrateError::Divergent { index: 1 })
+        );
+        // a missing sample is not a divergence, it propagates as NaN
+        let gap = [1., f64::NAN, 3., 4.];
+        assert!(try_trapezoid_f64(&gap, &x).unwrap().is_nan());
+        assert_eq!(
+            try_trapezoid_even_f32(&[f32::NEG_INFINITY, 1.], 1.),
+            Err(IntegrateError::Divergent { index: 0 })
+        );
+        // the unchecked variant keeps the IEEE result
+        assert!(trapezoid_f64(&y, &x).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_with_dx() {
+        use crate::sum::pairwise_fold;
+        let x: Vec<f64> = (0..10_007)
+            .map(|i| i as f64 * 1e-3 + (i as f64 * 0.37).sin() * 1e-4)
+            .collect();
+        let y: Vec<f64> = x.iter().map(|&x| (x * 3.).cos()).collect();
+        let dx: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+        let fused = trapezoid_signed_f64(&y, &x);
+        assert_eq!(trapezoid_with_dx_f64(&y, &dx).to_bits(), fused.to_bits());
+        // the former per-window fold, only the association order differs
+        let windows = pairwise_fold(0, y.len() - 1, &|acc, i| {
+            add_segment(acc, &y[i..i + 2], &x[i..i + 2])
+        });
+        assert!((fused - windows).abs() <= 1e-13 * windows.abs());
+        assert!((fused - (x[x.len() - 1] * 3.).sin() / 3.).abs() < 1e-6);
+        assert!(trapezoid_with_dx_f64(&y, &dx[1..]).is_nan());
+        assert!(trapezoid_with_dx_f32(&[1.], &[]).is_nan());
+    }
+}
@@ -0,0 +1,281 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 12/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::TrapezSample;
+use crate::error::IntegrateError;
+use crate::mla::fmla;
+use crate::sum::pairwise_fold;
+use crate::trapezoid::{Grid, classify, validate};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Integrates the product of `y` and a sampled weight `w` using the trapezoidal rule.
+///
+/// Computes `∫ y(x) w(x) dx` with every segment contributing
+/// `0.5 * (y[i] * w[i] + y[i + 1] * w[i + 1]) * (x[i + 1] - x[i])`,
+/// which is the building block for moments and expectations.
+/// `x` follows the same rules as in [trapezoid_f64](crate::trapezoid_f64).
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `w` - The array of weights sampled at the same abscissas.
+/// * `x` - The array of monotonic abscissas.
+///
+/// # Returns
+/// The approximate weighted integral, or NaN if the slices differ in length,
+/// have fewer than two samples, or `x` is not monotonic.
+pub fn trapezoid_weighted_f64(y: &[f64], w: &[f64], x: &[f64]) -> f64 {
+    trapezoid_weighted(y, w, x)
+}
+
+/// Integrates the product of `y` and a sampled weight `w` using the trapezoidal rule.
+///
+/// Computes `∫ y(x) w(x) dx` with every segment contributing
+/// `0.5 * (y[i] * w[i] + y[i + 1] * w[i + 1]) * (x[i + 1] - x[i])`,
+/// which is the building block for moments and expectations.
+/// `x` follows the same rules as in [trapezoid_f32](crate::trapezoid_f32).
+///
+/// # Arguments
+/// * `y` - The array of function values.
+/// * `w` - The array of weights sampled at the same abscissas.
+/// * `x` - The array of monotonic abscissas.
+///
+/// # Returns
+/// The approximate weighted integral, or NaN if the slices differ in length,
+/// have fewer than two samples, or `x` is not monotonic.
+pub fn trapezoid_weighted_f32(y: &[f32], w: &[f32], x: &[f32]) -> f32 {
+    trapezoid_weighted(y, w, x)
+}
+
+/// Integrates `y(x) * w(x)` with the weight evaluated at every abscissa.
+///
+/// Equivalent to [trapezoid_weighted_f64] with `w` sampled at `x`, without building
+/// the weight array. `w` is called exactly once per abscissa.
+///
+/// # Returns
+/// The approximate weighted integral, or NaN if the slices differ in length,
+/// have fewer than two samples, or `x` is not monotonic.
+pub fn trapezoid_weighted_fn_f64<W: Fn(f64) -> f64>(y: &[f64], x: &[f64], w: W) -> f64 {
+    trapezoid_weighted_fn(y, x, w)
+}
+
+/// Integrates `y(x) * w(x)` with the weight evaluated at every abscissa.
+///
+/// Equivalent to [trapezoid_weighted_f32] with `w` sampled at `x`, without building
+/// the weight array. `w` is called exactly once per abscissa.
+///
+/// # Returns
+/// The approximate weighted integral, or NaN if the slices differ in length,
+/// have fewer than two samples, or `x` is not monotonic.
+pub fn trapezoid_weighted_fn_f32<W: Fn(f32) -> f32>(y: &[f32], x: &[f32], w: W) -> f32 {
+    trapezoid_weighted_fn(y, x, w)
+}
+
+#[cfg(feature = "alloc")]
+/// The weight [trapezoid_f64](crate::trapezoid_f64) applies to every sample.
+///
+/// `dot(weights, y)` reproduces the integral up to the order of the additions.
+/// On a uniform grid with spacing `h` the weights are `h / 2, h, ..., h, h / 2`,
+/// otherwise sample `i` carries `0.5 * (x[i + 1] - x[i - 1])` and the end samples
+/// half of their outer interval. Descending grids give the same positive weights
+/// as the reversed grid.
+///
+/// # Returns
+/// `x.len()` weights, all NaN if `x` has fewer than two samples or is not monotonic.
+pub fn trapezoid_weights_f64(x: &[f64]) -> Vec<f64> {
+    trapezoid_weights(x)
+}
+
+#[cfg(feature = "alloc")]
+/// The weight [trapezoid_f32](crate::trapezoid_f32) applies to every sample.
+///
+/// `dot(weights, y)` reproduces the integral up to the order of the additions.
+/// On a uniform grid with spacing `h` the weights are `h / 2, h, ..., h, h / 2`,
+/// otherwise sample `i` carries `0.5 * (x[i + 1] - x[i - 1])` and the end samples
+/// half of their outer interval. Descending grids give the same positive weights
+/// as the reversed grid.
+///
+/// # Returns
+/// `x.len()` weights, all NaN if `x` has fewer than two samples or is not monotonic.
+pub fn trapezoid_weights_f32(x: &[f32]) -> Vec<f32> {
+    trapezoid_weights(x)
+}
+
+/// [trapezoid_weights_f64] writing into a caller-provided buffer.
+///
+/// # Errors
+/// * [IntegrateError::OutputLength] if `out.len() != x.len()`.
+/// * [IntegrateError::TooFewPoints] if `x` has fewer than two samples.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+pub fn trapezoid_weights_into_f64(x: &[f64], out: &mut [f64]) -> Result<(), IntegrateError> {
+    trapezoid_weights_into(x, out)
+}
+
+/// [trapezoid_weights_f32] writing into a caller-provided buffer.
+///
+/// # Errors
+/// * [IntegrateError::OutputLength] if `out.len() != x.len()`.
+/// * [IntegrateError::TooFewPoints] if `x` has fewer than two samples.
+/// * [IntegrateError::NonMonotonic] if `x` changes direction.
+pub fn trapezoid_weights_into_f32(x: &[f32], out: &mut [f32]) -> Result<(), IntegrateError> {
+    trapezoid_weights_into(x, out)
+}
+
+#[cfg(feature = "alloc")]
+fn trapezoid_weights<T: TrapezSample>(x: &[T]) -> Vec<T> {
+    let mut out = vec![T::nan(); x.len()];
+    if trapezoid_weights_into(x, &mut out).is_err() {
+        out.fill(T::nan());
+    }
+    out
+}
+
+fn trapezoid_weights_into<T: TrapezSample>(x: &[T], out: &mut [T]) -> Result<(), IntegrateError> {
+    let n = x.len();
+    if out.len() != n {
+        return Err(IntegrateError::OutputLength {
+            expected: n,
+            actual: out.len(),
+        });
+    }
+    let grid = classify(x)?;
+    for (i, w) in out.iter_mut().enumerate() {
+        *w = trapezoid_weight(x, grid, i);
+    }
+    Ok(())
+}
+
+/// Weight of sample `i` in the trapezoid rule over the classified grid `x`.
+///
+/// Sample `i` carries `0.5 * (x[i + 1] - x[i - 1])` and the end samples half of their
+/// outer interval, descending grids give the same positive weights as the reversed grid.
+#[inline]
+pub(crate) fn trapezoid_weight<T: TrapezSample>(x: &[T], grid: Grid<T>, i: usize) -> T {
+    let n = x.len();
+    let sign = if grid.descending { -T::one() } else { T::one() };
+    match grid.step {
+        Some(h0) if i == 0 || i == n - 1 => sign * h0 * T::HALF,
+        Some(h0) => sign * h0,
+        None => sign * T::HALF * (x[(i + 1).min(n - 1)] - x[i.saturating_sub(1)]),
+    }
+}
+
+fn trapezoid_weighted_fn<T: TrapezSample, W: Fn(T) -> T>(y: &[T], x: &[T], w: W) -> T {
+    if validate(y, x).is_err() {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    pairwise_fold(0, y.len(), &|acc, i| {
+        fmla(trapezoid_weight(x, grid, i), y[i] * w(x[i]), acc)
+    })
+}
+
+fn trapezoid_weighted<T: TrapezSample>(y: &[T], w: &[T], x: &[T]) -> T {
+    if w.len() != y.len() || validate(y, x).is_err() {
+        return T::nan();
+    }
+    let Ok(grid) = classify(x) else {
+        return T::nan();
+    };
+    let integral = pairwise_fold(0, y.len() - 1, &|acc, i| {
+        let dx = x[i + 1] - x[i];
+        fmla(dx * T::HALF, fmla(y[i], w[i], y[i + 1] * w[i + 1]), acc)
+    });
+    if grid.descending { -integral } else { integral }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trapezoid_f64;
+
+    #[test]
+    fn test_trapezoid_weighted() {
+        // Gaussian density centered at 1.5, its mean is 1.5
+        let x: [f64; 201] = core::array::from_fn(|i| -3.5 + i as f64 * 0.05);
+        let p = x.map(|x| (-0.5 * (x - 1.5) * (x - 1.5)).exp());
+        let mean = trapezoid_weighted_f64(&x, &p, &x) / trapezoid_f64(&p, &x);
+        assert!((mean - 1.5).abs() < 1e-9, "mean {mean}");
+
+        let ones = [1.; 6];
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        assert_eq!(trapezoid_weighted_f64(&y, &ones, &x), 30.5);
+        assert!(trapezoid_weighted_f64(&y, &ones[1..], &x).is_nan());
+    }
+
+    #[test]
+    fn test_trapezoid_weighted_fn() {
+        // standard normal density, its second moment is 1
+        let x: Vec<f64> = (0..=400).map(|i| -8. + i as f64 * 0.04).collect();
+        let p: Vec<f64> = x
+            .iter()
+            .map(|x| (-0.5 * x * x).exp() / (2. * core::f64::consts::PI).sqrt())
+            .collect();
+        let second = trapezoid_weighted_fn_f64(&p, &x, |x| x * x);
+        assert!((second - 1.).abs() < 1e-10, "{second}");
+
+        let y = [5., 6., 1., 4., 6., 2.];
+        let x = [1., 2., 4., 6., 7., 9.];
+        let w = x.map(|x| x * x);
+        let expected = trapezoid_weighted_f64(&y, &w, &x);
+        assert!((trapezoid_weighted_fn_f64(&y, &x, |x| x * x) - expected).abs() < 1e-12);
+        assert!(trapezoid_weighted_fn_f32(&[1.], &[1.], |x| x).is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_trapezoid_weights() {
+        let dot = |w: &[f64], y: &[f64]| w.iter().zip(y).map(|(w, y)| w * y).sum::<f64>();
+        let y = [5., 6., 1., 4., 6., 2.];
+        for x in [
+            [1., 2., 4., 6., 7., 9.],
+            [0., 0.5, 1., 1.5, 2., 2.5],
+            [9., 7., 6., 4., 2., 1.],
+        ] {
+            let w = trapezoid_weights_f64(&x);
+            assert_eq!(dot(&w, &y), trapezoid_f64(&y, &x));
+        }
+        assert_eq!(trapezoid_weights_f64(&[0., 1., 3.]), vec![0.5, 1.5, 1.]);
+        assert!(
+            trapezoid_weights_f32(&[0., 1., 0.5])
+                .iter()
+                .all(|w| w.is_nan())
+        );
+        let mut short = [0.; 2];
+        assert_eq!(
+            trapezoid_weights_into_f64(&[0., 1., 3.], &mut short),
+            Err(IntegrateError::OutputLength {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}